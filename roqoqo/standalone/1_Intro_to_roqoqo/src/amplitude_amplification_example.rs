@@ -0,0 +1,130 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::grover_example::multi_controlled_z;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+
+// Amplitude amplification generalizes Grover's search to an arbitrary state preparation A.
+// A prepares |psi> = A|0> = sin(theta) |good> + cos(theta) |bad>, the oracle S_f flips the phase of
+// the good subspace and S_0 = I - 2|0><0| flips the phase of |0...0>.
+// The reflection about the prepared state is A S_0 A^dagger = I - 2|psi><psi|: A^dagger maps |psi> back
+// to |0...0>, S_0 flips its phase, and A maps it forward again.
+// Each application of Q = -A S_0 A^dagger S_f rotates the state by 2 theta towards the good subspace, so after
+// k iterations the good subspace is measured with probability sin^2((2k + 1) theta).
+// The overall sign of Q is a global phase and is left out of the circuit.
+// Grover's search is the special case where A is a layer of Hadamard gates.
+
+/// Returns the inverse of `circuit`, the gates in reverse order with each gate inverted.
+///
+/// Supports the rotations `RotateX`, `RotateY`, `RotateZ` and the self-inverse gates `Hadamard`,
+/// `PauliX` and `CNOT`.
+///
+pub fn inverse_circuit(circuit: &Circuit) -> Circuit {
+    let mut inverse = Circuit::new();
+    let minus_one = CalculatorFloat::from(-1.0);
+    let operations: Vec<&Operation> = circuit.iter().collect();
+    for operation in operations.into_iter().rev() {
+        match operation {
+            Operation::RotateX(op) => inverse += op.powercf(minus_one.clone()),
+            Operation::RotateY(op) => inverse += op.powercf(minus_one.clone()),
+            Operation::RotateZ(op) => inverse += op.powercf(minus_one.clone()),
+            Operation::Hadamard(_) | Operation::PauliX(_) | Operation::CNOT(_) => {
+                inverse.add_operation(operation.clone())
+            }
+            _ => panic!("Inversion of {} is not supported", operation.hqslang()),
+        }
+    }
+    inverse
+}
+
+/// Builds the amplitude amplification circuit: `prep` followed by `iterations` applications of Q.
+///
+/// `good_oracle` has to flip the phase of the good states. The reflection S_0 acts on all qubits
+/// involved in `prep`.
+///
+pub fn amplify(prep: Circuit, good_oracle: Circuit, iterations: usize) -> Circuit {
+    let mut qubits: Vec<usize> = match prep.involved_qubits() {
+        InvolvedQubits::Set(set) => set.into_iter().collect(),
+        _ => panic!("State preparation has to act on a fixed set of qubits"),
+    };
+    qubits.sort_unstable();
+
+    // Phase flip of |0...0>: map it to |1...1>, flip the phase, and map it back
+    let mut zero_reflection = Circuit::new();
+    for qubit in qubits.iter() {
+        zero_reflection += PauliX::new(*qubit);
+    }
+    zero_reflection += multi_controlled_z(&qubits);
+    for qubit in qubits.iter() {
+        zero_reflection += PauliX::new(*qubit);
+    }
+
+    // Q up to the global sign: S_f first, then the reflection about A|0...0>
+    let mut iteration = Circuit::new();
+    iteration += good_oracle;
+    iteration += inverse_circuit(&prep);
+    iteration += zero_reflection;
+    iteration += prep.clone();
+
+    let mut circuit = prep;
+    for _ in 0..iterations {
+        circuit += iteration.clone();
+    }
+    circuit
+}
+
+/// Returns the exact probability that both qubits of a two-qubit `circuit` are measured in |1>.
+fn good_probability(circuit: &Circuit) -> f64 {
+    let mut readout = Circuit::new();
+    readout += DefinitionComplex::new("psi".to_string(), 4, true);
+    readout += circuit.clone();
+    readout += PragmaGetStateVector::new("psi".to_string(), None);
+
+    let backend = Backend::new(2);
+    let (_result_bit_registers, _result_float_registers, result_complex_registers) =
+        backend.run_circuit(&readout).unwrap();
+    result_complex_registers["psi"][0][0b11].norm_sqr()
+}
+
+/// Example amplifying the good state |11> of a non-uniform two-qubit superposition.
+pub fn aa_main() {
+    println!(">> Amplitude amplification example start.");
+    // A prepares a product state in which |11> has the small probability sin^2(0.5) sin^2(0.6)
+    let mut prep = Circuit::new();
+    prep += RotateY::new(0, CalculatorFloat::from(1.0));
+    prep += RotateY::new(1, CalculatorFloat::from(1.2));
+    let good_oracle = multi_controlled_z(&[0, 1]);
+
+    let initial_probability = good_probability(&prep);
+    let theta = initial_probability.sqrt().asin();
+    let mut probabilities = Vec::new();
+    for iterations in 0..4 {
+        let probability = good_probability(&amplify(prep.clone(), good_oracle.clone(), iterations));
+        println!(
+            "Probability of the good state after {} iterations: {:.6}",
+            iterations, probability
+        );
+
+        // Validation check: the probability follows sin^2((2k + 1) theta)
+        let expected = ((2 * iterations + 1) as f64 * theta).sin().powi(2);
+        assert!((probability - expected).abs() < 1e-10);
+        probabilities.push(probability);
+    }
+
+    // Validation check: the probability grows up to the optimal number of iterations
+    assert!(probabilities[0] < probabilities[1] && probabilities[1] < probabilities[2]);
+    assert!(probabilities[2] > 0.95);
+    println!(">> Amplitude amplification example end.");
+}
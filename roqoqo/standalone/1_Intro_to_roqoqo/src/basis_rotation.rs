@@ -0,0 +1,34 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::{operations::*, Circuit};
+
+// Projective measurements read out qubits in the Z-basis. To measure X or Y the eigenstates of that Pauli
+// operator are first rotated onto the eigenstates of Z, then a Z-measurement gives the same statistics:
+// X = H Z H, so a Hadamard maps |+> to |0> and |-> to |1>.
+// Y = S H Z H S^dagger, so S^dagger followed by a Hadamard maps |+i> to |0> and |-i> to |1>.
+
+/// Appends the basis change to `circuit` that turns a Z-measurement of `qubit` into a measurement of `pauli`.
+///
+/// `pauli` is one of 'X', 'Y' or 'Z', no operation is needed for 'Z'.
+///
+pub fn append_basis_change(circuit: &mut Circuit, qubit: usize, pauli: char) {
+    match pauli {
+        'X' => *circuit += Hadamard::new(qubit),
+        'Y' => {
+            *circuit += InvSGate::new(qubit);
+            *circuit += Hadamard::new(qubit);
+        }
+        'Z' => {}
+        _ => panic!("Unknown Pauli operator {}, expected 'X', 'Y' or 'Z'", pauli),
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, SQRT_2};
+
+// The CHSH inequality bounds the combination of correlators
+// S = E(a, b) - E(a, b') + E(a', b) + E(a', b')
+// by |S| <= 2 for every local hidden variable theory. Measuring the Bell state (|00> + |11>) / sqrt(2)
+// along directions in the x-z plane gives E(a, b) = cos(a - b), which reaches S = 2 sqrt(2) for the
+// angles below.
+
+/// Measurement angle of the first setting on qubit 0.
+pub const ANGLE_A: f64 = 0.0;
+/// Measurement angle of the second setting on qubit 0.
+pub const ANGLE_A_PRIME: f64 = FRAC_PI_2;
+/// Measurement angle of the first setting on qubit 1.
+pub const ANGLE_B: f64 = FRAC_PI_4;
+/// Measurement angle of the second setting on qubit 1.
+pub const ANGLE_B_PRIME: f64 = 3.0 * FRAC_PI_4;
+
+/// Estimates the correlator E(a, b) of the Bell state from `number_measurements` projective measurements.
+///
+/// Rotating a qubit with `RotateY(-angle)` before the Z-measurement measures the
+/// observable cos(angle) Z + sin(angle) X. The correlator is the average of the product of
+/// the two measured eigenvalues ±1.
+///
+pub fn correlator(angle_a: f64, angle_b: f64, number_measurements: usize) -> f64 {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    circuit += RotateY::new(0, CalculatorFloat::from(-angle_a));
+    circuit += RotateY::new(1, CalculatorFloat::from(-angle_b));
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), number_measurements, None);
+
+    let backend = Backend::new(2);
+    let (result_bit_registers, _result_float_registers, _result_complex_registers) =
+        backend.run_circuit(&circuit).unwrap();
+
+    let sum: f64 = result_bit_registers["ro"]
+        .iter()
+        .map(|measurement| {
+            if measurement[0] == measurement[1] {
+                1.0
+            } else {
+                -1.0
+            }
+        })
+        .sum();
+    sum / number_measurements as f64
+}
+
+/// Estimates the CHSH value S for the angle settings defined in this module.
+pub fn chsh_value(number_measurements: usize) -> f64 {
+    correlator(ANGLE_A, ANGLE_B, number_measurements)
+        - correlator(ANGLE_A, ANGLE_B_PRIME, number_measurements)
+        + correlator(ANGLE_A_PRIME, ANGLE_B, number_measurements)
+        + correlator(ANGLE_A_PRIME, ANGLE_B_PRIME, number_measurements)
+}
+
+/// Example violating the CHSH inequality with a simulated Bell pair.
+pub fn chsh_main() {
+    println!(">> CHSH example start.");
+    let number_measurements: usize = 10000;
+    let chsh = chsh_value(number_measurements);
+    println!(
+        "Measured S = {:.4} (classical bound 2, quantum maximum 2 sqrt(2) = {:.4})",
+        chsh,
+        2.0 * SQRT_2
+    );
+
+    // Validation check: the classical bound is violated and S is close to the Tsirelson bound
+    assert!(chsh > 2.0);
+    assert!((chsh - 2.0 * SQRT_2).abs() < 0.1);
+    println!(">> CHSH example end.");
+}
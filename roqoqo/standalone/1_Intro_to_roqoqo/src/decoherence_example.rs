@@ -0,0 +1,97 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::collections::HashMap;
+
+// Decoherence of a qubit waiting idle on hardware is described by two time scales:
+// T1 for the decay of the excited state |1> to |0> (amplitude damping) and T2 for the decay of
+// the coherence between |0> and |1>. In roqoqo both are added to a circuit as noise pragmas:
+// `PragmaDamping` with the rate 1/T1 and `PragmaDephasing` with the pure dephasing rate.
+// The QuEST backend switches to a density matrix simulation as soon as a noise pragma is present.
+// The coherence of the state |+> is its expectation value < X >, which decays as exp(-t / T2).
+
+/// Duration of one idle step, in the same units as T1 and T2.
+pub const IDLE_TIME: f64 = 1.0;
+
+/// Circuit preparing |+> and letting the qubit decohere for `idle_steps` idle steps.
+///
+/// The damping contributes 1/(2 T1) to the decay rate of the coherence, the remaining
+/// 1/T2 - 1/(2 T1) is added by the dephasing. The dephasing pragma decays the coherence
+/// with twice its rate, which requires T2 <= 2 T1.
+///
+pub fn idle_circuit(t1: f64, t2: f64, idle_steps: usize) -> Circuit {
+    assert!(t2 <= 2.0 * t1, "T2 can not be larger than 2 T1");
+    let dephasing_rate = (1.0 / t2 - 1.0 / (2.0 * t1)) / 2.0;
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    for _ in 0..idle_steps {
+        circuit += PragmaDamping::new(0, IDLE_TIME.into(), (1.0 / t1).into());
+        circuit += PragmaDephasing::new(0, IDLE_TIME.into(), dephasing_rate.into());
+    }
+    circuit
+}
+
+/// Returns (time, coherence) pairs for 0 to `steps` idle steps of a qubit starting in |+>.
+///
+/// The qubit is rotated to the X-basis with a Hadamard gate and the exact expectation value < Z > is read out
+/// with a `PragmaGetPauliProduct`, so the curve is free of shot noise.
+///
+pub fn decay_curve(t1: f64, t2: f64, steps: usize) -> Vec<(f64, f64)> {
+    (0..=steps)
+        .map(|idle_steps| {
+            let mut circuit = Circuit::new();
+            circuit += DefinitionFloat::new("coherence".to_string(), 1, true);
+            circuit += idle_circuit(t1, t2, idle_steps);
+            circuit += Hadamard::new(0);
+            circuit += PragmaGetPauliProduct::new(
+                HashMap::from([(0, 3)]),
+                "coherence".to_string(),
+                Circuit::new(),
+            );
+            let backend = Backend::new(1);
+            let (_result_bit_registers, result_float_registers, _result_complex_registers) =
+                backend.run_circuit(&circuit).unwrap();
+            (
+                idle_steps as f64 * IDLE_TIME,
+                result_float_registers["coherence"][0][0],
+            )
+        })
+        .collect()
+}
+
+/// Example showing the decay of the coherence of |+> under amplitude damping and dephasing.
+pub fn decoherence_main() {
+    println!(">> Decoherence example start.");
+    let t1 = 20.0;
+    let t2 = 10.0;
+    let curve = decay_curve(t1, t2, 10);
+    for (time, coherence) in curve.iter() {
+        println!(
+            "t = {:4.1}: < X > = {:.4}, exp(-t / T2) = {:.4}",
+            time,
+            coherence,
+            (-time / t2).exp()
+        );
+    }
+
+    // Validation check: the coherence decreases monotonically and follows exp(-t / T2)
+    for window in curve.windows(2) {
+        assert!(window[1].1 < window[0].1);
+    }
+    for (time, coherence) in curve.iter() {
+        assert!((coherence - (-time / t2).exp()).abs() < 1e-6);
+    }
+    println!(">> Decoherence example end.");
+}
@@ -0,0 +1,104 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::state_printing::print_statevector;
+use crate::verification::statevectors_equal_up_to_phase;
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::f64::consts::FRAC_PI_2;
+
+// Hardware usually only implements a small set of native gates, other gates have to be rewritten in terms
+// of native ones before a circuit can run. In this example the native gate set consists of single-qubit
+// rotations, `PauliX` and `ControlledPauliZ`:
+// Hadamard = PauliX RotateY(pi/2), applying first the rotation and then the PauliX gate.
+// CNOT(c, t) = RotateY_t(pi/2) ControlledPauliZ(c, t) RotateY_t(-pi/2): the rotations on the target
+// turn the Z conditioned on the control into an X conditioned on the control.
+
+/// Rewrites `circuit` in the native gate set, replacing `Hadamard` and `CNOT` gates.
+///
+/// All other operations, including definitions and measurements, are copied unchanged.
+///
+pub fn decompose_to_native(circuit: &Circuit) -> Circuit {
+    let mut native = Circuit::new();
+    for operation in circuit.iter() {
+        match operation {
+            Operation::Hadamard(op) => {
+                native += RotateY::new(*op.qubit(), CalculatorFloat::from(FRAC_PI_2));
+                native += PauliX::new(*op.qubit());
+            }
+            Operation::CNOT(op) => {
+                native += RotateY::new(*op.target(), CalculatorFloat::from(-FRAC_PI_2));
+                native += ControlledPauliZ::new(*op.control(), *op.target());
+                native += RotateY::new(*op.target(), CalculatorFloat::from(FRAC_PI_2));
+            }
+            _ => native.add_operation(operation.clone()),
+        }
+    }
+    native
+}
+
+/// Runs `circuit` on `num_qubits` qubits and returns the final state vector.
+fn run_state_vector(num_qubits: usize, circuit: &Circuit) -> Vec<Complex64> {
+    let mut readout = Circuit::new();
+    readout += DefinitionComplex::new("psi".to_string(), 2_usize.pow(num_qubits as u32), true);
+    readout += circuit.clone();
+    readout += PragmaGetStateVector::new("psi".to_string(), None);
+
+    let backend = Backend::new(num_qubits);
+    let (_result_bit_registers, _result_float_registers, result_complex_registers) =
+        backend.run_circuit(&readout).unwrap();
+    result_complex_registers["psi"][0].clone()
+}
+
+/// Example decomposing circuits into the native gate set and comparing the resulting state vectors.
+pub fn decomposition_main() {
+    println!(">> Gate decomposition example start.");
+    let mut bell = Circuit::new();
+    bell += Hadamard::new(0);
+    bell += CNOT::new(0, 1);
+    let native_bell = decompose_to_native(&bell);
+    println!("Bell circuit: {}", bell);
+    println!("Decomposed Bell circuit: {}", native_bell);
+    let original = run_state_vector(2, &bell);
+    let decomposed = run_state_vector(2, &native_bell);
+    println!("State vector of the original circuit:");
+    print_statevector(&original, 2);
+    println!("State vector of the decomposed circuit:");
+    print_statevector(&decomposed, 2);
+
+    // Validation check: the decompositions are exact, so the Bell states agree element by element
+    for (x, y) in original.iter().zip(decomposed.iter()) {
+        assert!((x - y).norm() < 1e-10);
+    }
+
+    // Validation check: a small circuit with rotations in between gives the same state up to a global phase
+    let mut small = Circuit::new();
+    small += RotateX::new(1, CalculatorFloat::from(0.3));
+    small += Hadamard::new(1);
+    small += CNOT::new(1, 0);
+    small += RotateZ::new(0, CalculatorFloat::from(1.1));
+    small += Hadamard::new(0);
+    small += CNOT::new(0, 1);
+    let native_small = decompose_to_native(&small);
+    assert!(!native_small
+        .iter()
+        .any(|operation| matches!(operation, Operation::Hadamard(_) | Operation::CNOT(_))));
+    assert!(statevectors_equal_up_to_phase(
+        &run_state_vector(2, &small),
+        &run_state_vector(2, &native_small),
+        1e-10
+    ));
+    println!(">> Gate decomposition example end.");
+}
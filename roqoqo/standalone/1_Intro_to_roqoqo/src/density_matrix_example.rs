@@ -0,0 +1,88 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+
+// Noise turns pure states into mixed states, which cannot be described by a state vector. The density matrix
+// rho describes both, and its purity Tr(rho^2) is 1 for pure states and 1/d for the maximally mixed state in d
+// dimensions. The QuEST backend switches to a density matrix simulation when a circuit contains noise pragmas.
+// Here a qubit prepared in |1> decays with `PragmaDamping`: after the damping probability
+// p = 1 - exp(-rate * time) we have rho = diag(p, 1 - p) with purity p^2 + (1 - p)^2.
+// The purity falls from 1 to 1/2 at p = 1/2. For even stronger damping the qubit relaxes towards the
+// pure state |0> and the purity rises again, so the sweep stops at p = 1/2.
+
+/// Duration of the damping.
+const GATE_TIME: f64 = 1.0;
+
+/// Computes the purity Tr(rho^2) of the `dim` x `dim` density matrix `rho` stored as a flat vector.
+pub fn purity(rho: &[Complex64], dim: usize) -> f64 {
+    assert_eq!(rho.len(), dim * dim, "Density matrix needs dim^2 entries");
+    let mut trace = Complex64::new(0.0, 0.0);
+    for i in 0..dim {
+        for j in 0..dim {
+            trace += rho[i * dim + j] * rho[j * dim + i];
+        }
+    }
+    trace.re
+}
+
+/// Returns the density matrix of a qubit prepared in |1> and damped with `rate`.
+fn damped_density_matrix(rate: f64) -> Vec<Complex64> {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionComplex::new("rho".to_string(), 4, true);
+    circuit += PauliX::new(0);
+    if rate > 0.0 {
+        circuit += PragmaDamping::new(
+            0,
+            CalculatorFloat::from(GATE_TIME),
+            CalculatorFloat::from(rate),
+        );
+    }
+    circuit += PragmaGetDensityMatrix::new("rho".to_string(), None);
+
+    let backend = Backend::new(1);
+    let (_result_bit_registers, _result_float_registers, result_complex_registers) =
+        backend.run_circuit(&circuit).unwrap();
+    result_complex_registers["rho"][0].clone()
+}
+
+/// Example sweeping the damping rate and printing the purity of the resulting mixed state.
+pub fn density_main() {
+    println!(">> Density matrix example start.");
+    // Rates from no damping up to the damping probability p = 1/2
+    let rates = [0.0, 0.1, 0.2, 0.3, 0.5, 2_f64.ln()];
+    let mut purities = Vec::new();
+    for rate in rates {
+        let rho = damped_density_matrix(rate);
+        let purity = purity(&rho, 2);
+        println!(
+            "Damping rate {:.4}: populations |0> {:.4} and |1> {:.4}, purity {:.6}",
+            rate, rho[0].re, rho[3].re, purity
+        );
+        // Validation check: the purity agrees with p^2 + (1 - p)^2
+        let damping_probability = 1.0 - (-rate * GATE_TIME).exp();
+        let expected = damping_probability.powi(2) + (1.0 - damping_probability).powi(2);
+        assert!((purity - expected).abs() < 1e-10);
+        purities.push(purity);
+    }
+
+    // Validation check: the noiseless state is pure, any damping makes it mixed
+    assert!((purities[0] - 1.0).abs() < 1e-10);
+    assert!(purities[1] < 1.0);
+    assert!(purities.windows(2).all(|pair| pair[1] < pair[0]));
+    assert!((purities[purities.len() - 1] - 0.5).abs() < 1e-10);
+    println!(">> Density matrix example end.");
+}
@@ -0,0 +1,88 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+
+// The Deutsch-Jozsa algorithm decides with a single oracle query whether a function f: {0, 1}^n -> {0, 1}
+// is constant or balanced (see also the standalone Deutsch-Josza example for a detailed walkthrough).
+// The oracle maps |x>|y> to |x>|y oplus f(x)> with the n input qubits 0..n and the ancilla qubit n.
+// Preparing the ancilla in |-> kicks the phase (-1)^f(x) back onto the inputs, and the final Hadamard
+// gates interfere all amplitudes into |0...0> for a constant function and away from it for a balanced one.
+
+/// Oracle of the constant function f(x) = 1, flipping the ancilla independently of the input.
+pub fn constant_oracle(n: usize) -> Circuit {
+    let mut oracle = Circuit::new();
+    oracle += PauliX::new(n);
+    oracle
+}
+
+/// Oracle of the balanced function f(x) = x_0 oplus ... oplus x_(n-1), the parity of the input.
+pub fn balanced_oracle(n: usize) -> Circuit {
+    let mut oracle = Circuit::new();
+    for qubit in 0..n {
+        oracle += CNOT::new(qubit, n);
+    }
+    oracle
+}
+
+/// Builds the Deutsch-Jozsa circuit for `n` input qubits with repeated measurements into the readout `ro`.
+///
+/// The repeated measurement reads out all qubits, the ancilla qubit n is the last entry of each measurement.
+///
+pub fn deutsch_jozsa_circuit(n: usize, oracle: Circuit, number_measurements: usize) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), n + 1, true);
+    circuit += PauliX::new(n);
+    for qubit in 0..=n {
+        circuit += Hadamard::new(qubit);
+    }
+    circuit += oracle;
+    for qubit in 0..n {
+        circuit += Hadamard::new(qubit);
+    }
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), number_measurements, None);
+    circuit
+}
+
+/// Runs the Deutsch-Jozsa algorithm and returns the measured probability of the all-zeros outcome.
+pub fn all_zeros_probability(n: usize, oracle: Circuit, number_measurements: usize) -> f64 {
+    let circuit = deutsch_jozsa_circuit(n, oracle, number_measurements);
+    let backend = Backend::new(n + 1);
+    let (result_bit_registers, _result_float_registers, _result_complex_registers) =
+        backend.run_circuit(&circuit).unwrap();
+    let all_zeros = result_bit_registers["ro"]
+        .iter()
+        .filter(|measurement| measurement[..n].iter().all(|bit| !bit))
+        .count();
+    all_zeros as f64 / number_measurements as f64
+}
+
+/// Example distinguishing the constant and the balanced oracle with the Deutsch-Jozsa algorithm.
+pub fn dj_main() {
+    println!(">> Deutsch-Jozsa example start.");
+    let number_measurements: usize = 100;
+    for n in [2, 4] {
+        let constant = all_zeros_probability(n, constant_oracle(n), number_measurements);
+        let balanced = all_zeros_probability(n, balanced_oracle(n), number_measurements);
+        println!(
+            "{} input qubits: P(0...0) = {:.2} for the constant oracle, {:.2} for the balanced oracle",
+            n, constant, balanced
+        );
+
+        // Validation check: the all-zeros outcome is certain for the constant and impossible for the balanced oracle
+        assert_eq!(constant, 1.0);
+        assert_eq!(balanced, 0.0);
+    }
+    println!(">> Deutsch-Jozsa example end.");
+}
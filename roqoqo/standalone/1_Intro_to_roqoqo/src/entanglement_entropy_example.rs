@@ -0,0 +1,110 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use nalgebra::DMatrix;
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::f64::consts::LN_2;
+
+// The entanglement of a pure two-qubit state is measured by the von Neumann entropy S = -Tr(rho_A ln rho_A)
+// of the reduced density matrix rho_A of one qubit. rho_A is obtained from the full density matrix by the
+// partial trace over the other qubit, and the entropy follows from the eigenvalues p_i of rho_A as
+// S = -sum_i p_i ln p_i. A product state has a pure reduced state with S = 0, a Bell state has the maximally
+// mixed reduced state I/2 with S = ln 2.
+
+/// Eigenvalues below this threshold do not contribute to the entropy (p ln p -> 0).
+const EIGENVALUE_THRESHOLD: f64 = 1e-12;
+
+/// Computes the von Neumann entropy of one qubit of the two-qubit density matrix `rho` (flat, row-major).
+///
+/// The qubit `trace_out` (0 or 1) is traced out and the entropy of the remaining qubit is returned.
+///
+pub fn reduced_entropy(rho: &[Complex64], trace_out: usize) -> f64 {
+    assert_eq!(rho.len(), 16, "Two-qubit density matrix needs 16 entries");
+    assert!(trace_out < 2, "Traced out qubit has to be 0 or 1");
+    let kept = 1 - trace_out;
+    // Basis index of the state with the kept qubit in `a` and the traced out qubit in `k`
+    let index = |a: usize, k: usize| (a << kept) | (k << trace_out);
+
+    let mut reduced = DMatrix::<Complex64>::zeros(2, 2);
+    for a in 0..2 {
+        for b in 0..2 {
+            for k in 0..2 {
+                reduced[(a, b)] += rho[index(a, k) * 4 + index(b, k)];
+            }
+        }
+    }
+    reduced
+        .symmetric_eigen()
+        .eigenvalues
+        .iter()
+        .filter(|p| **p > EIGENVALUE_THRESHOLD)
+        .map(|p| -p * p.ln())
+        .sum()
+}
+
+/// Returns the density matrix of the two-qubit state prepared by `circuit`.
+fn density_matrix(circuit: &Circuit) -> Vec<Complex64> {
+    let mut readout = Circuit::new();
+    readout += DefinitionComplex::new("rho".to_string(), 16, true);
+    readout += circuit.clone();
+    readout += PragmaGetDensityMatrix::new("rho".to_string(), None);
+
+    let backend = Backend::new(2);
+    let (_result_bit_registers, _result_float_registers, result_complex_registers) =
+        backend.run_circuit(&readout).unwrap();
+    result_complex_registers["rho"][0].clone()
+}
+
+/// Example computing the entanglement entropy of product, partially entangled and Bell states.
+pub fn entropy_main() {
+    println!(">> Entanglement entropy example start.");
+    let mut product = Circuit::new();
+    product += Hadamard::new(0);
+    product += RotateY::new(1, CalculatorFloat::from(0.7));
+
+    // cos(theta/2) |00> + sin(theta/2) |11> with a varying amount of entanglement
+    let theta: f64 = 1.0;
+    let mut partial = Circuit::new();
+    partial += RotateY::new(0, CalculatorFloat::from(theta));
+    partial += CNOT::new(0, 1);
+
+    let mut bell = Circuit::new();
+    bell += Hadamard::new(0);
+    bell += CNOT::new(0, 1);
+
+    let product_entropy = reduced_entropy(&density_matrix(&product), 1);
+    let partial_entropy = reduced_entropy(&density_matrix(&partial), 1);
+    let bell_rho = density_matrix(&bell);
+    let bell_entropy = reduced_entropy(&bell_rho, 1);
+    println!("Entropy of the product state: {:.6}", product_entropy);
+    println!(
+        "Entropy of the partially entangled state: {:.6}",
+        partial_entropy
+    );
+    println!(
+        "Entropy of the Bell state: {:.6} (ln 2 = {:.6})",
+        bell_entropy, LN_2
+    );
+
+    // Validation check: no entanglement in the product state, maximal entanglement in the Bell state
+    assert!(product_entropy.abs() < 1e-10);
+    assert!((bell_entropy - LN_2).abs() < 1e-10);
+    assert!((reduced_entropy(&bell_rho, 0) - LN_2).abs() < 1e-10);
+    let p: f64 = (theta / 2.0).cos().powi(2);
+    let expected = -p * p.ln() - (1.0 - p) * (1.0 - p).ln();
+    assert!((partial_entropy - expected).abs() < 1e-10);
+    println!(">> Entanglement entropy example end.");
+}
@@ -0,0 +1,84 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::collections::HashMap;
+
+// The Greenberger-Horne-Zeilinger (GHZ) state (|0...0> + |1...1>) / sqrt(2) generalizes the Bell state
+// to N qubits. It is prepared by a Hadamard gate on the first qubit followed by a chain of CNOT gates
+// spreading the superposition over all qubits.
+
+/// Probability above which a bitstring other than all-zeros or all-ones indicates a construction bug.
+const NEGLIGIBLE_PROBABILITY: f64 = 0.01;
+
+/// Builds the circuit preparing the `num_qubits`-qubit GHZ state.
+pub fn ghz_circuit(num_qubits: usize) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(0);
+    for qubit in 0..num_qubits.saturating_sub(1) {
+        circuit += CNOT::new(qubit, qubit + 1);
+    }
+    circuit
+}
+
+/// Measures the GHZ state on `num_qubits` qubits and returns the measured probability of each bitstring.
+pub fn ghz_distribution(num_qubits: usize, number_measurements: usize) -> HashMap<Vec<bool>, f64> {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), num_qubits, true);
+    circuit += ghz_circuit(num_qubits);
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), number_measurements, None);
+
+    let backend = Backend::new(num_qubits);
+    let (result_bit_registers, _result_float_registers, _result_complex_registers) =
+        backend.run_circuit(&circuit).unwrap();
+
+    let mut distribution: HashMap<Vec<bool>, f64> = HashMap::new();
+    for measurement in result_bit_registers["ro"].iter() {
+        *distribution.entry(measurement.clone()).or_insert(0.0) += 1.0 / number_measurements as f64;
+    }
+    distribution
+}
+
+/// Example preparing GHZ states of different sizes and checking their measured distributions.
+pub fn ghz_main() {
+    println!(">> GHZ example start.");
+    let number_measurements: usize = 2000;
+    for number_qubits in [3, 5, 8] {
+        let distribution = ghz_distribution(number_qubits, number_measurements);
+        let all_zeros = vec![false; number_qubits];
+        let all_ones = vec![true; number_qubits];
+        let probability_zeros = distribution.get(&all_zeros).copied().unwrap_or(0.0);
+        let probability_ones = distribution.get(&all_ones).copied().unwrap_or(0.0);
+        println!(
+            "{} qubits: P(0...0) = {:.4}, P(1...1) = {:.4}",
+            number_qubits, probability_zeros, probability_ones
+        );
+        for (bitstring, probability) in distribution.iter() {
+            if bitstring != &all_zeros
+                && bitstring != &all_ones
+                && *probability > NEGLIGIBLE_PROBABILITY
+            {
+                println!(
+                    "Warning: intermediate bitstring {:?} has the probability {:.4}",
+                    bitstring, probability
+                );
+            }
+        }
+
+        // Validation check: only the two GHZ components are measured, with roughly equal weight
+        assert!((probability_zeros + probability_ones - 1.0).abs() < 1e-10);
+        assert!((probability_zeros - 0.5).abs() < 0.05);
+    }
+    println!(">> GHZ example end.");
+}
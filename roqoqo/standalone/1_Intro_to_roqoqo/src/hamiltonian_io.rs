@@ -0,0 +1,198 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::measurements::PauliZProductInput;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+
+// Reading qubit Hamiltonians generated by external tools, *e.g.* quantum chemistry packages, from JSON files:
+// {
+//   "number_qubits": 2,
+//   "constant": -0.33,
+//   "terms": [{ "qubits": [0, 1], "paulis": ["X", "X"], "coefficient": 0.18 }, ...]
+// }
+// All Pauli labels of a term are either "Z" or "X". Z-terms are measured from the readout `ro_z` of the
+// unrotated circuit, X-terms from the readout `ro_x` of the circuit rotated to the X-basis with Hadamard gates.
+// The constant is added as the Pauli product with an empty mask, which always evaluates to one.
+// The Hamiltonian is the expectation value `energy` of the returned measurement input.
+
+/// Returns the field `key` of the JSON `object`, or an error mentioning `context`.
+fn field<'a>(object: &'a Value, key: &str, context: &str) -> Result<&'a Value, String> {
+    object
+        .get(key)
+        .ok_or_else(|| format!("{} is missing the field '{}'", context, key))
+}
+
+/// Reads a Pauli Hamiltonian from the JSON file at `path` and builds its measurement input.
+///
+/// Returns an error if the file cannot be read or its content is rejected by [parse_pauli_hamiltonian].
+///
+pub fn load_pauli_hamiltonian(path: &Path) -> Result<PauliZProductInput, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|error| format!("Cannot read {}: {}", path.display(), error))?;
+    parse_pauli_hamiltonian(&content).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+/// Builds the measurement input of the Pauli Hamiltonian given as the JSON string `content`.
+///
+/// Returns an error if `content` is not valid JSON, an entry is malformed
+/// or a qubit index is outside of the `number_qubits` qubits.
+///
+pub fn parse_pauli_hamiltonian(content: &str) -> Result<PauliZProductInput, String> {
+    let json: Value =
+        serde_json::from_str(content).map_err(|error| format!("Invalid JSON: {}", error))?;
+
+    let number_qubits = field(&json, "number_qubits", "Hamiltonian")?
+        .as_u64()
+        .ok_or("Field 'number_qubits' has to be a non-negative integer")?
+        as usize;
+    let constant = field(&json, "constant", "Hamiltonian")?
+        .as_f64()
+        .ok_or("Field 'constant' has to be a number")?;
+    let terms = field(&json, "terms", "Hamiltonian")?
+        .as_array()
+        .ok_or("Field 'terms' has to be a list")?;
+
+    let mut measurement_input = PauliZProductInput::new(number_qubits, false);
+    let identity = measurement_input
+        .add_pauliz_product("ro_z".to_string(), vec![])
+        .map_err(|error| error.to_string())?;
+    let mut linear = HashMap::from([(identity, constant)]);
+    for (index, term) in terms.iter().enumerate() {
+        let context = format!("Term {}", index);
+        let qubits = field(term, "qubits", &context)?
+            .as_array()
+            .ok_or_else(|| format!("{}: 'qubits' has to be a list", context))?
+            .iter()
+            .map(|qubit| {
+                let qubit = qubit.as_u64().ok_or_else(|| {
+                    format!(
+                        "{}: qubit indices have to be non-negative integers",
+                        context
+                    )
+                })? as usize;
+                if qubit >= number_qubits {
+                    return Err(format!(
+                        "{}: qubit index {} is out of range for {} qubits",
+                        context, qubit, number_qubits
+                    ));
+                }
+                Ok(qubit)
+            })
+            .collect::<Result<Vec<usize>, String>>()?;
+        let paulis = field(term, "paulis", &context)?
+            .as_array()
+            .ok_or_else(|| format!("{}: 'paulis' has to be a list", context))?
+            .iter()
+            .map(|pauli| {
+                pauli
+                    .as_str()
+                    .ok_or_else(|| format!("{}: Pauli labels have to be strings", context))
+            })
+            .collect::<Result<Vec<&str>, String>>()?;
+        let coefficient = field(term, "coefficient", &context)?
+            .as_f64()
+            .ok_or_else(|| format!("{}: 'coefficient' has to be a number", context))?;
+
+        if qubits.is_empty() || qubits.len() != paulis.len() {
+            return Err(format!(
+                "{}: needs one Pauli label for each of at least one qubit, got {} qubits and {} labels",
+                context,
+                qubits.len(),
+                paulis.len()
+            ));
+        }
+        let readout = match paulis[0] {
+            "Z" => "ro_z",
+            "X" => "ro_x",
+            label => {
+                return Err(format!(
+                    "{}: unsupported Pauli label '{}', expected 'Z' or 'X'",
+                    context, label
+                ))
+            }
+        };
+        if paulis.iter().any(|pauli| *pauli != paulis[0]) {
+            return Err(format!(
+                "{}: all Pauli labels of a term have to be equal, got {:?}",
+                context, paulis
+            ));
+        }
+        let product = measurement_input
+            .add_pauliz_product(readout.to_string(), qubits)
+            .map_err(|error| format!("{}: {}", context, error))?;
+        *linear.entry(product).or_insert(0.0) += coefficient;
+    }
+    measurement_input
+        .add_linear_exp_val("energy".to_string(), linear)
+        .map_err(|error| error.to_string())?;
+    Ok(measurement_input)
+}
+
+/// Example checking the parsing of Hamiltonians with a known expectation value and on malformed input.
+pub fn hamiltonian_io_main() {
+    println!(">> Hamiltonian loading example start.");
+
+    // Validation check: H = 0.5 + 2 Z0 + X0 X1 gives 0.5 - 2 + 1 for the shots Z0 = -1 and X0 X1 = +1
+    let small = parse_pauli_hamiltonian(
+        r#"{"number_qubits": 2, "constant": 0.5, "terms": [
+            {"qubits": [0], "paulis": ["Z"], "coefficient": 2.0},
+            {"qubits": [0, 1], "paulis": ["X", "X"], "coefficient": 1.0}]}"#,
+    )
+    .unwrap();
+    let registers = HashMap::from([
+        ("ro_z".to_string(), vec![vec![true, false]]),
+        ("ro_x".to_string(), vec![vec![true, true]]),
+    ]);
+    assert_eq!(
+        crate::expectation_values(&small, &registers)["energy"],
+        -0.5
+    );
+
+    // Validation check: malformed entries and out-of-range qubits are rejected with a clear error
+    let out_of_range = parse_pauli_hamiltonian(
+        r#"{"number_qubits": 2, "constant": 0.0, "terms": [
+            {"qubits": [2], "paulis": ["Z"], "coefficient": 1.0}]}"#,
+    )
+    .unwrap_err();
+    println!("Out-of-range qubit: {}", out_of_range);
+    assert!(out_of_range.contains("qubit index 2 is out of range"));
+    let missing_field = parse_pauli_hamiltonian(
+        r#"{"number_qubits": 2, "constant": 0.0, "terms": [{"qubits": [0], "paulis": ["Z"]}]}"#,
+    )
+    .unwrap_err();
+    println!("Malformed term: {}", missing_field);
+    assert!(missing_field.contains("Term 0 is missing the field 'coefficient'"));
+    let invalid_json = parse_pauli_hamiltonian("{\"number_qubits\": 2,").unwrap_err();
+    assert!(invalid_json.starts_with("Invalid JSON"));
+
+    // Validation check: the file errors name the path
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let missing_file = load_pauli_hamiltonian(&crate_dir.join("missing.json")).unwrap_err();
+    println!("Missing file: {}", missing_file);
+    assert!(missing_file.starts_with("Cannot read") && missing_file.contains("missing.json"));
+
+    // Validation check: the H2 Hamiltonian file of this crate gives the hand-computed energy
+    // -0.332404 + 0.397937 (-1) - 0.397937 (+1) - 0.011280 (-1) + 0.180931 (+1) = -0.936067
+    // for the shots Z0 = -1, Z1 = +1 and X0 X1 = +1
+    let h2 = load_pauli_hamiltonian(&crate_dir.join("h2.json")).unwrap();
+    assert_eq!(h2.number_qubits, 2);
+    let registers = HashMap::from([
+        ("ro_z".to_string(), vec![vec![true, false]]),
+        ("ro_x".to_string(), vec![vec![false, false]]),
+    ]);
+    let energy = crate::expectation_values(&h2, &registers)["energy"];
+    println!("Energy of h2.json for known registers: {:.6}", energy);
+    assert!((energy - (-0.936067)).abs() < 1e-10);
+    println!(">> Hamiltonian loading example end.");
+}
@@ -0,0 +1,97 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::state_printing::print_statevector;
+use ndarray::Array1;
+use num_complex::Complex64;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::f64::consts::FRAC_1_SQRT_2;
+
+// A circuit normally starts in the state |0...0>. On a simulator, `PragmaSetStateVector` replaces the
+// state by an arbitrary amplitude vector, e.g. to start directly from an entangled state without
+// the gates preparing it. The pragma is not available on quantum hardware.
+// Here two qubits are initialized to a given state, a CNOT(0, 1) is applied and the final state vector
+// is read out. Starting from the Bell state (|00> + |11>) / sqrt(2) the CNOT gives the product state
+// |0>_1 (|0>_0 + |1>_0) / sqrt(2) (qubit 0 is the rightmost in the basis labels).
+
+/// Number of qubits of the initialized register.
+const NUMBER_QUBITS: usize = 2;
+
+/// Maximal deviation of the norm from one accepted for an initial state.
+const NORM_TOLERANCE: f64 = 1e-10;
+
+/// Initializes the qubits to `initial`, applies a CNOT(0, 1) and returns the final state vector.
+///
+/// Returns an error if `initial` does not have length 2^n for the two qubits or is not normalized.
+///
+pub fn prepare_and_run(initial: Vec<Complex64>) -> Result<Vec<Complex64>, String> {
+    let dimension = 2_usize.pow(NUMBER_QUBITS as u32);
+    if initial.len() != dimension {
+        return Err(format!(
+            "Initial state has {} amplitudes, a state of {} qubits needs {}",
+            initial.len(),
+            NUMBER_QUBITS,
+            dimension
+        ));
+    }
+    let norm = initial.iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
+    if (norm - 1.0).abs() > NORM_TOLERANCE {
+        return Err(format!(
+            "Initial state is not normalized: its norm is {:.6}",
+            norm
+        ));
+    }
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionComplex::new("psi".to_string(), dimension, true);
+    circuit += PragmaSetStateVector::new(Array1::from(initial));
+    circuit += CNOT::new(0, 1);
+    circuit += PragmaGetStateVector::new("psi".to_string(), None);
+
+    let backend = Backend::new(NUMBER_QUBITS);
+    let (_result_bit_registers, _result_float_registers, result_complex_registers) = backend
+        .run_circuit(&circuit)
+        .map_err(|error| format!("Running the circuit failed: {:?}", error))?;
+    Ok(result_complex_registers["psi"][0].clone())
+}
+
+/// Example starting a circuit from a Bell state set with `PragmaSetStateVector`.
+pub fn initial_state_main() {
+    println!(">> Initial state example start.");
+    let amplitude = Complex64::new(FRAC_1_SQRT_2, 0.0);
+    let zero = Complex64::new(0.0, 0.0);
+    let bell = vec![amplitude, zero, zero, amplitude];
+    println!("Initial Bell state:");
+    print_statevector(&bell, NUMBER_QUBITS);
+    let final_state = prepare_and_run(bell).unwrap();
+    println!("State after the CNOT:");
+    print_statevector(&final_state, NUMBER_QUBITS);
+
+    // Validation check: the CNOT disentangles the Bell state into |0>_1 (|0>_0 + |1>_0) / sqrt(2)
+    let expected = [amplitude, amplitude, zero, zero];
+    for (x, y) in final_state.iter().zip(expected.iter()) {
+        assert!((x - y).norm() < 1e-10);
+    }
+
+    // Validation check: invalid initial states are rejected with an error
+    let unnormalized = vec![amplitude, zero, zero, zero];
+    let error = prepare_and_run(unnormalized).unwrap_err();
+    println!("Unnormalized initial state: {}", error);
+    assert!(error.contains("not normalized"));
+    let wrong_length = vec![amplitude, amplitude];
+    let error = prepare_and_run(wrong_length).unwrap_err();
+    println!("Initial state of wrong length: {}", error);
+    assert!(error.contains("needs 4"));
+    println!(">> Initial state example end.");
+}
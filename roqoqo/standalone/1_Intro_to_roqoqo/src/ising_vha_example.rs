@@ -0,0 +1,162 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::simple_vha_with_roqoqo::compass_search;
+use nalgebra::DMatrix;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::measurements::{PauliZProduct, PauliZProductInput};
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::collections::HashMap;
+
+// Variational Hamiltonian ansatz for the open transverse-field Ising chain
+// H = -J sum_i Z_i Z_(i+1) - h sum_i X_i.
+// The ZZ couplings are diagonal in the Z-basis and are measured directly. The X field terms are not:
+// a Pauli X expectation value is measured by rotating the qubit with a Hadamard gate (H X H = Z)
+// before the projective measurement, so they need a second measurement circuit in the X-basis.
+// Both circuits prepare the same ansatz state and only differ in the basis change before the readout.
+// The chain needs at least one spin, the functions panic with an explicit message for `num_spins` = 0.
+
+/// Number of projective measurements per measurement circuit.
+const NUMBER_MEASUREMENTS: usize = 4000;
+
+/// Measurement input of the Ising Hamiltonian on `num_spins` spins with the coupling `j` and the field `h`.
+///
+/// The ZZ terms are read out from the register `ro_z` of the Z-basis circuit, the X terms from the register
+/// `ro_x` of the circuit rotated to the X-basis.
+///
+pub fn ising_hamiltonian(num_spins: usize, j: f64, h: f64) -> PauliZProductInput {
+    assert!(num_spins >= 1, "The Ising chain needs at least one spin");
+    let mut measurement_input = PauliZProductInput::new(num_spins, false);
+    let mut linear: HashMap<usize, f64> = HashMap::new();
+    for spin in 0..num_spins - 1 {
+        let index = measurement_input
+            .add_pauliz_product("ro_z".to_string(), vec![spin, spin + 1])
+            .unwrap();
+        linear.insert(index, -j);
+    }
+    for spin in 0..num_spins {
+        let index = measurement_input
+            .add_pauliz_product("ro_x".to_string(), vec![spin])
+            .unwrap();
+        linear.insert(index, -h);
+    }
+    measurement_input
+        .add_linear_exp_val("energy".to_string(), linear)
+        .unwrap();
+    measurement_input
+}
+
+/// Number of variational parameters of one ansatz layer: one per coupling and one per spin.
+pub fn parameters_per_layer(num_spins: usize) -> usize {
+    assert!(num_spins >= 1, "The Ising chain needs at least one spin");
+    2 * num_spins - 1
+}
+
+/// Layered ansatz for the Ising chain.
+///
+/// The chain starts in |+...+>, the ground state of the field term. Each layer applies the evolution
+/// exp(-i theta/2 Z_i Z_(i+1)) of each coupling, a `RotateZ` between two CNOT gates, followed by a `RotateX`
+/// on each spin for the field term.
+///
+pub fn ising_ansatz(params: &[f64], num_spins: usize) -> Circuit {
+    assert_eq!(params.len() % parameters_per_layer(num_spins), 0);
+    let mut circuit = Circuit::new();
+    for spin in 0..num_spins {
+        circuit += Hadamard::new(spin);
+    }
+    for layer in params.chunks(parameters_per_layer(num_spins)) {
+        let (couplings, fields) = layer.split_at(num_spins - 1);
+        for (spin, angle) in couplings.iter().enumerate() {
+            circuit += CNOT::new(spin, spin + 1);
+            circuit += RotateZ::new(spin + 1, CalculatorFloat::from(*angle));
+            circuit += CNOT::new(spin, spin + 1);
+        }
+        for (spin, angle) in fields.iter().enumerate() {
+            circuit += RotateX::new(spin, CalculatorFloat::from(*angle));
+        }
+    }
+    circuit
+}
+
+/// Measures the energy of the ansatz state for the parameters `params`.
+pub fn ising_energy(params: &[f64], num_spins: usize, j: f64, h: f64) -> f64 {
+    let mut z_basis = Circuit::new();
+    z_basis += DefinitionBit::new("ro_z".to_string(), num_spins, true);
+    z_basis += ising_ansatz(params, num_spins);
+    z_basis += PragmaRepeatedMeasurement::new("ro_z".to_string(), NUMBER_MEASUREMENTS, None);
+
+    // Basis change: the Hadamard gates map the eigenstates of X to the computational basis states
+    let mut x_basis = Circuit::new();
+    x_basis += DefinitionBit::new("ro_x".to_string(), num_spins, true);
+    x_basis += ising_ansatz(params, num_spins);
+    for spin in 0..num_spins {
+        x_basis += Hadamard::new(spin);
+    }
+    x_basis += PragmaRepeatedMeasurement::new("ro_x".to_string(), NUMBER_MEASUREMENTS, None);
+
+    let measurement = PauliZProduct {
+        input: ising_hamiltonian(num_spins, j, h),
+        circuits: vec![z_basis, x_basis],
+        constant_circuit: None,
+    };
+    let backend = Backend::new(num_spins);
+    backend.run_measurement(&measurement).unwrap().unwrap()["energy"]
+}
+
+/// Calculates the exact ground state energy of the Ising chain by diagonalizing the Hamiltonian.
+pub fn exact_ising_energy(num_spins: usize, j: f64, h: f64) -> f64 {
+    assert!(num_spins >= 1, "The Ising chain needs at least one spin");
+    let dimension = 2_usize.pow(num_spins as u32);
+    let mut hamiltonian = DMatrix::<f64>::zeros(dimension, dimension);
+    for state in 0..dimension {
+        for spin in 0..num_spins - 1 {
+            // Z_i Z_(i+1) is +1 for equal and -1 for different spins
+            let equal = (state >> spin) & 1 == (state >> (spin + 1)) & 1;
+            hamiltonian[(state, state)] += if equal { -j } else { j };
+        }
+        for spin in 0..num_spins {
+            // X_i flips spin i
+            hamiltonian[(state ^ (1 << spin), state)] += -h;
+        }
+    }
+    hamiltonian.symmetric_eigen().eigenvalues.min()
+}
+
+/// Optimizes the Ising ansatz with `number_layers` layers and returns the converged energy.
+pub fn run_ising_vha(num_spins: usize, j: f64, h: f64, number_layers: usize) -> f64 {
+    // All angles zero leave |+...+> unchanged, a stationary point of the energy, so we start away from it
+    let initial = vec![0.3; number_layers * parameters_per_layer(num_spins)];
+    let (parameters, _) = compass_search(|params| ising_energy(params, num_spins, j, h), initial);
+    println!("Optimized parameters theta: {:?}", parameters);
+    // A fresh evaluation avoids the bias of the optimizer towards favourable shot noise
+    ising_energy(&parameters, num_spins, j, h)
+}
+
+/// Example finding the ground state energy of a transverse-field Ising chain with a VHA.
+pub fn ising_vha_main() {
+    println!(">> Ising VHA example start.");
+    let num_spins: usize = 3;
+    let j = 1.0;
+    let h = 1.0;
+    let energy = run_ising_vha(num_spins, j, h, 2);
+    let exact_energy = exact_ising_energy(num_spins, j, h);
+    println!(
+        "Ising chain with {} spins, J = {}, h = {}: VHA energy {:.4}, exact energy {:.4}",
+        num_spins, j, h, energy, exact_energy
+    );
+
+    // Validation check: the VHA energy agrees with the exact ground state energy up to shot noise
+    assert!((energy - exact_energy).abs() < 0.25);
+    println!(">> Ising VHA example end.");
+}
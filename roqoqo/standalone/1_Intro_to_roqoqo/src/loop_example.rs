@@ -0,0 +1,145 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::symbolic_parameters_example::substitute;
+use crate::verification::statevectors_equal_up_to_phase;
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::collections::HashMap;
+
+// A `PragmaLoop` repeats a sub-circuit a given number of times, so repeated blocks are stored only once.
+// The number of repetitions is a `CalculatorFloat` and can be a symbolic parameter that is substituted
+// before the circuit runs, like the symbolic rotation angles in the symbolic parameters example.
+// Limitation: the QuEST backend does not implement `PragmaLoop`. The loops are therefore expanded into
+// the repeated operations with `expand_loops` before the circuit is simulated, which requires a numeric
+// repetition count.
+
+/// Entangling block that is repeated: a rotation of qubit 0 followed by a CNOT onto qubit 1.
+fn entangling_block() -> Circuit {
+    let mut block = Circuit::new();
+    block += RotateX::new(0, CalculatorFloat::from(0.4));
+    block += CNOT::new(0, 1);
+    block
+}
+
+/// Circuit repeating the entangling block `repetitions` times with a `PragmaLoop`.
+pub fn looped_circuit(repetitions: usize) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += PragmaLoop::new(
+        CalculatorFloat::from(repetitions as f64),
+        entangling_block(),
+    );
+    circuit
+}
+
+/// Circuit repeating the entangling block `repetitions` times by appending it explicitly.
+pub fn unrolled_circuit(repetitions: usize) -> Circuit {
+    let mut circuit = Circuit::new();
+    for _ in 0..repetitions {
+        circuit += entangling_block();
+    }
+    circuit
+}
+
+/// Replaces every `PragmaLoop` in `circuit` by the repeated operations of its sub-circuit.
+///
+/// Nested loops are expanded as well. Panics if a repetition count is still symbolic.
+///
+pub fn expand_loops(circuit: &Circuit) -> Circuit {
+    let mut expanded = Circuit::new();
+    for operation in circuit.iter() {
+        match operation {
+            Operation::PragmaLoop(pragma) => {
+                let repetitions = *pragma
+                    .repetitions()
+                    .float()
+                    .expect("Loop repetitions have to be substituted before the loop is expanded");
+                let body = expand_loops(pragma.circuit());
+                for _ in 0..repetitions as usize {
+                    expanded += body.clone();
+                }
+            }
+            _ => expanded.add_operation(operation.clone()),
+        }
+    }
+    expanded
+}
+
+/// Returns the final state vector of `circuit` on two qubits.
+fn run_state_vector(circuit: &Circuit) -> Vec<Complex64> {
+    let mut readout = Circuit::new();
+    readout += DefinitionComplex::new("psi".to_string(), 4, true);
+    readout += circuit.clone();
+    readout += PragmaGetStateVector::new("psi".to_string(), None);
+
+    let backend = Backend::new(2);
+    let (_result_bit_registers, _result_float_registers, result_complex_registers) =
+        backend.run_circuit(&readout).unwrap();
+    result_complex_registers["psi"][0].clone()
+}
+
+/// Example comparing a looped circuit with its manually unrolled equivalent.
+pub fn loop_main() {
+    println!(">> Loop example start.");
+    let repetitions: usize = 5;
+    let looped = looped_circuit(repetitions);
+    let unrolled = unrolled_circuit(repetitions);
+    println!(
+        "Operations in the looped circuit: {}, in the unrolled circuit: {}",
+        looped.len(),
+        unrolled.len()
+    );
+
+    let looped_state = run_state_vector(&expand_loops(&looped));
+    let unrolled_state = run_state_vector(&unrolled);
+    let looped_probabilities: Vec<f64> = looped_state.iter().map(|x| x.norm_sqr()).collect();
+    let unrolled_probabilities: Vec<f64> = unrolled_state.iter().map(|x| x.norm_sqr()).collect();
+    println!(
+        "Measurement distribution of the looped circuit: {:?}",
+        looped_probabilities
+    );
+    println!(
+        "Measurement distribution of the unrolled circuit: {:?}",
+        unrolled_probabilities
+    );
+
+    // Validation check: both forms prepare the same state and give the same measurement distribution
+    assert!(statevectors_equal_up_to_phase(
+        &looped_state,
+        &unrolled_state,
+        1e-12
+    ));
+    for (p, q) in looped_probabilities
+        .iter()
+        .zip(unrolled_probabilities.iter())
+    {
+        assert!((p - q).abs() < 1e-12);
+    }
+    assert_eq!(looped.len(), 1);
+    assert_eq!(unrolled.len(), 2 * repetitions);
+
+    // Validation check: a symbolic repetition count gives the same state after substitution
+    let mut symbolic = Circuit::new();
+    symbolic += PragmaLoop::new(CalculatorFloat::from("repetitions"), entangling_block());
+    let parameters = HashMap::from([("repetitions".to_string(), repetitions as f64)]);
+    let substituted = substitute(&symbolic, &parameters);
+    println!("Substituted symbolic loop: {}", substituted);
+    assert!(statevectors_equal_up_to_phase(
+        &run_state_vector(&expand_loops(&substituted)),
+        &unrolled_state,
+        1e-12
+    ));
+    println!(">> Loop example end.");
+}
@@ -10,22 +10,73 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use roqoqo::backends::{EvaluatingBackend, RegisterResult};
-use roqoqo::measurements::{PauliZProduct, PauliZProductInput};
+use num_complex::Complex64;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::measurements::{MeasureExpectationValues, PauliZProduct, PauliZProductInput};
 use roqoqo::{operations::*, registers::*, Circuit, QuantumProgram};
 use roqoqo_quest::Backend;
+use runner::{CircuitRunner, MockRunner, QuestRunner};
 use std::collections::{HashMap, HashSet};
+use std::f64::consts::FRAC_1_SQRT_2;
+
+mod amplitude_amplification_example;
+mod basis_rotation;
+mod bernstein_vazirani_example;
+mod chsh_example;
+mod decoherence_example;
+mod decomposition_example;
+mod density_matrix_example;
+mod deutsch_jozsa_example;
+mod entanglement_entropy_example;
+mod ghz_example;
+mod grover_example;
+mod hamiltonian_io;
+mod initial_state_example;
+mod ising_vha_example;
+mod loop_example;
+mod measurement_builders;
+mod metrics;
+mod mid_circuit_measurement_example;
+mod noisy_vha_example;
+mod output_csv;
+mod parallel_eval;
+mod parameter_shift;
+mod parametric_program_example;
+mod phase_estimation_example;
+mod process_fidelity_example;
+mod qaoa_maxcut_example;
+mod qasm_export;
+mod qft_example;
+mod qrng_example;
+mod quantum_classifier_example;
+mod readout_error_example;
+mod runner;
+mod serialization_formats;
+mod shot_statistics_example;
+mod simple_vha_with_roqoqo;
+mod state_printing;
+mod superdense_coding_example;
+mod swap_test_example;
+mod symbolic_parameters_example;
+mod toffoli_example;
+mod trotter_example;
+mod verification;
+mod vqe_h2_example;
 
 // Introduction examples for simple circuits and measurements.
 // For extended examples on "Fine control over decoherence", usage of "Symbolic parameters",
 // or "Testing performance with qoqo_mock" backend,
 // please refer to Intro examples in Jupyter notebooks.
 
+/// Default number of projective measurements in the measurement examples.
+pub const NUMBER_MEASUREMENTS: usize = 10;
+
 /// Example to entangle a circuit snippet.
 ///
 /// Similar to many other toolkits the unitary entangling circuit can be constructed by adding operations to a circuit.
+/// The circuit is returned for further use, *e.g.* the export to OpenQASM.
 ///
-pub fn entangling_circuit_snippet() {
+pub fn entangling_circuit_snippet() -> Circuit {
     println!(">> Introduction example start.");
     // Create a new modifiable circuit
     let mut circuit = Circuit::new();
@@ -49,15 +100,46 @@ pub fn entangling_circuit_snippet() {
 
     // Compare the derived circuit length to the expected one for this example
     assert_eq!(circuit.len(), 3);
+
+    // Read out and print the state vector prepared by the circuit
+    let mut readout_circuit = circuit.clone();
+    readout_circuit += DefinitionComplex::new("psi".to_string(), 4, true);
+    readout_circuit += PragmaGetStateVector::new("psi".to_string(), None);
+    let backend = Backend::new(2);
+    let (_result_bit_registers, _result_float_registers, result_complex_registers) =
+        backend.run_circuit(&readout_circuit).unwrap();
+    let amplitudes = &result_complex_registers["psi"][0];
+    println!("State vector of the circuit:");
+    state_printing::print_statevector(amplitudes, 2);
+    println!("Probabilities of the basis states:");
+    state_printing::print_probabilities(amplitudes, 2);
+
+    // Validation check: the formatted Bell state (|00> + |11>) / sqrt(2) contains exactly the two basis labels
+    let bell_state = [
+        Complex64::new(FRAC_1_SQRT_2, 0.0),
+        Complex64::new(0.0, 0.0),
+        Complex64::new(0.0, 0.0),
+        Complex64::new(FRAC_1_SQRT_2, 0.0),
+    ];
+    let formatted =
+        state_printing::format_statevector(&bell_state, 2, state_printing::DEFAULT_THRESHOLD);
+    assert_eq!(formatted.len(), 2);
+    assert!(formatted[0].starts_with("|00⟩"));
+    assert!(formatted[1].starts_with("|11⟩"));
+    circuit
 }
 
 /// Example for measuring qubits.
 ///
 /// roqoqo uses classical registers for the readout. We need to add a classical register definition to the circuit and a measurement statement.
-/// The number of projective measurements can be directly set in the circuit.  
-/// The simulation and measurement of the circuit is handled by the roqoqo_quest interface (in this example).
+/// The number of projective measurements `number_measurements` can be directly set in the circuit.  
+/// The simulation and measurement of the circuit is handled by the `runner`, *e.g.* a `QuestRunner` for the roqoqo_quest interface.
+/// The measured bit registers are returned for further use, *e.g.* the export to CSV.
 ///
-pub fn measuring_qubits() {
+pub fn measuring_qubits(
+    runner: &dyn CircuitRunner,
+    number_measurements: usize,
+) -> HashMap<String, BitOutputRegister> {
     // Create new modifiable circuit
     let mut circuit = Circuit::new();
     // Define classical bit register for the readout of the measurement
@@ -67,49 +149,50 @@ pub fn measuring_qubits() {
     circuit += Hadamard::new(0);
     circuit += CNOT::new(0, 1);
     // Add operation to the circuit to perform repeated measurements in a quantum computing simulation.
-    circuit += PragmaRepeatedMeasurement::new(register_name, 10, None);
+    circuit += PragmaRepeatedMeasurement::new(register_name, number_measurements, None);
     println!(
         ">> Circuit prepared for a simulated measurement: {:?}",
         circuit,
     );
 
-    let backend = Backend::new(2);
-    let result: RegisterResult = backend.run_circuit(&circuit);
-    let result_registers: (
-        HashMap<String, BitOutputRegister>,
-        HashMap<String, FloatOutputRegister>,
-        HashMap<String, ComplexOutputRegister>,
-    ) = result.unwrap();
+    let result_bit_registers: HashMap<String, BitOutputRegister> = runner.run(&circuit).unwrap();
 
     println!(">> Bit output register 'ro' contains the following single projective measurements:");
-    for single_projective_measurements in &result_registers.0["ro"] {
+    for single_projective_measurements in &result_bit_registers["ro"] {
         println!("{:?}", single_projective_measurements);
     }
+    result_bit_registers
 }
 
 /// Example for measuring observables.
 ///
 /// roqoqo includes the direct evaluation of projective measurements to an observable measurement *e.g.* 3 * < Z0 > + < Z0 Z1 >.
 /// The measurement is defined by a set of expectation values of a product of pauli operators and a matrix that combines the expectation values.
+/// The pauli products are registered with `measurement_builders::add_pauli_z_product`.
+/// Afterwards < X0 > and < Y0 > are measured by rotating the qubit to the Z-basis with `basis_rotation`.
+/// The result estimated from `number_measurements` projective measurements is returned.
 ///
-pub fn measuring_observables() {
+pub fn measuring_observables(number_measurements: usize) -> f64 {
     let mut circuit = Circuit::new();
     circuit.add_operation(DefinitionBit::new("ro".to_string(), 2, true));
     circuit.add_operation(Hadamard::new(0));
     circuit.add_operation(CNOT::new(0, 1));
-    circuit.add_operation(PragmaRepeatedMeasurement::new("ro".to_string(), 10, None));
+    circuit.add_operation(PragmaRepeatedMeasurement::new(
+        "ro".to_string(),
+        number_measurements,
+        None,
+    ));
 
     let mut measurement_input = PauliZProductInput::new(2, false);
     // From readout 'ro' measure two pauli products 0: < Z0 > and 1: < Z0 Z1 >
-    measurement_input
-        .add_pauliz_product("ro".to_string(), vec![0])
-        .unwrap();
-    measurement_input
-        .add_pauliz_product("ro".to_string(), vec![0, 1])
-        .unwrap();
+    let z0 = measurement_builders::add_pauli_z_product(&mut measurement_input, &[0]);
+    let z0z1 = measurement_builders::add_pauli_z_product(&mut measurement_input, &[0, 1]);
     // One expectation value: 3 * pauli_product0 + 1 * pauli_product1
     measurement_input
-        .add_linear_exp_val("example".to_string(), HashMap::from([(0, 3.0), (1, 1.0)]))
+        .add_linear_exp_val(
+            "example".to_string(),
+            HashMap::from([(z0, 3.0), (z0z1, 1.0)]),
+        )
         .unwrap();
     println!(">> Measurement input defined: {:?}", measurement_input,);
 
@@ -131,6 +214,122 @@ pub fn measuring_observables() {
     // Validation check
     assert!(result > -4.0 * 10.0);
     assert!(result < 4.0 * 10.0);
+
+    // X and Y are measured after a basis change, here for the eigenstates |+> of X and |+i> of Y
+    let mut plus_state = Circuit::new();
+    plus_state += Hadamard::new(0);
+    let mut plus_i_state = Circuit::new();
+    plus_i_state += Hadamard::new(0);
+    plus_i_state += SGate::new(0);
+    for (name, preparation, pauli) in [("X0", plus_state, 'X'), ("Y0", plus_i_state, 'Y')] {
+        let mut circuit = Circuit::new();
+        circuit += DefinitionBit::new("ro".to_string(), 1, true);
+        circuit += preparation;
+        basis_rotation::append_basis_change(&mut circuit, 0, pauli);
+        circuit += PragmaRepeatedMeasurement::new("ro".to_string(), number_measurements, None);
+        let (result_bit_registers, _result_float_registers, _result_complex_registers) =
+            Backend::new(1).run_circuit(&circuit).unwrap();
+        let value = expectation_values(
+            &measurement_builders::single_pauli_z(0, name),
+            &result_bit_registers,
+        )[name];
+        println!(">> Measured < {} > of its eigenstate: {:?}", name, value);
+
+        // Validation check: the eigenstate with eigenvalue +1 gives the expectation value 1
+        assert!((value - 1.0).abs() < 1e-10);
+    }
+    result
+}
+
+/// Example for the statistical convergence of an observable measurement.
+///
+/// The measurement of 3 * < Z0 > + < Z0 Z1 > from example 1.3 is repeated with an increasing number of projective measurements.
+/// For the Bell state the analytic value is 3 * 0 + 1 = 1, the deviation of the estimate shrinks with 1/sqrt(number of measurements).
+///
+pub fn shot_noise_convergence() {
+    let analytic_value = 1.0;
+    for number_measurements in [100, 1000, 10000] {
+        let estimate = measuring_observables(number_measurements);
+        println!(
+            ">> {} measurements: estimate {:.4}, deviation from the analytic value {:.4}",
+            number_measurements,
+            estimate,
+            (estimate - analytic_value).abs()
+        );
+    }
+}
+
+/// Evaluates all expectation values defined in `input` from one batch of measured bit registers.
+///
+/// Every Pauli product registered in `input` is calculated from the same projective measurements.
+/// No circuit is run, the measurements in `bit_registers` are reused for all expectation values.
+///
+pub fn expectation_values(
+    input: &PauliZProductInput,
+    bit_registers: &HashMap<String, BitOutputRegister>,
+) -> HashMap<String, f64> {
+    let measurement = PauliZProduct {
+        input: input.clone(),
+        circuits: vec![],
+        constant_circuit: None,
+    };
+    measurement
+        .evaluate(bit_registers.clone(), HashMap::new(), HashMap::new())
+        .unwrap()
+        .unwrap()
+}
+
+/// Example for measuring several observables from the same set of measurements.
+///
+/// All Pauli Z products are diagonal in the Z-basis, so a single batch of projective measurements in that basis
+/// contains the information for every product: for each shot the product of the measured +1/-1 values of the
+/// involved qubits is averaged. Observables in different bases, *e.g.* X0 X1, need another measurement circuit.
+/// The circuits are run with `runner` and the batched expectation values are returned.
+///
+pub fn measuring_batched_observables(runner: &dyn CircuitRunner) -> HashMap<String, f64> {
+    let number_measurements: usize = 5000;
+    let theta_0: f64 = 0.6;
+    let theta_1: f64 = 1.9;
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += RotateY::new(0, theta_0.into());
+    circuit += RotateY::new(1, theta_1.into());
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), number_measurements, None);
+
+    // One expectation value for each of the products < Z0 >, < Z1 > and < Z0 Z1 >
+    let products: [(&str, Vec<usize>); 3] =
+        [("Z0", vec![0]), ("Z1", vec![1]), ("Z0Z1", vec![0, 1])];
+    let mut measurement_input = PauliZProductInput::new(2, false);
+    for (name, qubits) in products.iter() {
+        let index = measurement_input
+            .add_pauliz_product("ro".to_string(), qubits.clone())
+            .unwrap();
+        measurement_input
+            .add_linear_exp_val(name.to_string(), HashMap::from([(index, 1.0)]))
+            .unwrap();
+    }
+
+    let result_bit_registers = runner.run(&circuit).unwrap();
+    let batched = expectation_values(&measurement_input, &result_bit_registers);
+    println!(
+        ">> Expectation values from one batch of measurements: {:?}",
+        batched
+    );
+
+    // Validation check: compare with each observable measured individually with its own shots
+    for (name, qubits) in products.iter() {
+        let mut single_input = PauliZProductInput::new(2, false);
+        let index = single_input
+            .add_pauliz_product("ro".to_string(), qubits.clone())
+            .unwrap();
+        single_input
+            .add_linear_exp_val(name.to_string(), HashMap::from([(index, 1.0)]))
+            .unwrap();
+        let individual = expectation_values(&single_input, &runner.run(&circuit).unwrap())[*name];
+        println!("Individually measured < {} >: {:.4}", name, individual);
+        assert!((batched[*name] - individual).abs() < 0.1);
+    }
+    batched
 }
 
 /// De/Serializing the quantum program
@@ -184,9 +383,190 @@ pub fn serialization_quantum_program() {
     println!(">> Introduction example end.")
 }
 
-fn main() {
+/// Runs the introduction examples 1.1 and 1.4 in sequence.
+///
+/// The measurement examples 1.2 and 1.3 are run separately by `measurement_main`, so running all
+/// examples does not repeat them.
+///
+fn intro_main() {
     entangling_circuit_snippet();
-    measuring_qubits();
-    measuring_observables();
     serialization_quantum_program();
 }
+
+/// Runs the measurement examples for single qubits and observables.
+///
+/// The circuits run on the QuEST simulator. The same examples then process canned results of a `MockRunner`.
+///
+fn measurement_main() {
+    let runner = QuestRunner::new(2);
+    let bell_registers = measuring_qubits(&runner, NUMBER_MEASUREMENTS);
+    output_csv::output_csv_main(&bell_registers);
+    measuring_observables(NUMBER_MEASUREMENTS);
+    measuring_batched_observables(&runner);
+    shot_noise_convergence();
+
+    // Validation check: with the canned shots |11> and |00> the expectation values are known exactly
+    let mock = MockRunner {
+        registers: HashMap::from([("ro".to_string(), vec![vec![true, true], vec![false, false]])]),
+    };
+    assert_eq!(measuring_qubits(&mock, 2), mock.registers);
+    let mocked = measuring_batched_observables(&mock);
+    assert_eq!(mocked["Z0"], 0.0);
+    assert_eq!(mocked["Z1"], 0.0);
+    assert_eq!(mocked["Z0Z1"], 1.0);
+
+    // Validation check: the measurement builders evaluate correctly on a known bit register
+    let known_registers = HashMap::from([(
+        "ro".to_string(),
+        vec![
+            vec![true, false],
+            vec![true, false],
+            vec![false, false],
+            vec![true, true],
+        ],
+    )]);
+    let z0_input = measurement_builders::single_pauli_z(0, "Z0");
+    assert_eq!(expectation_values(&z0_input, &known_registers)["Z0"], -0.5);
+    let z1_input = measurement_builders::pauli_z_product(&[1], "2Z1", 2.0);
+    assert_eq!(expectation_values(&z1_input, &known_registers)["2Z1"], 1.0);
+    let z0z1_input = measurement_builders::pauli_z_product(&[0, 1], "Z0Z1", 1.0);
+    assert_eq!(
+        expectation_values(&z0z1_input, &known_registers)["Z0Z1"],
+        0.0
+    );
+
+    // Validation check: products added to one input are combined inside roqoqo
+    let mut combined_input = PauliZProductInput::new(2, false);
+    let z0 = measurement_builders::add_pauli_z_product(&mut combined_input, &[0]);
+    let z0z1 = measurement_builders::add_pauli_z_product(&mut combined_input, &[0, 1]);
+    assert_eq!((z0, z0z1), (0, 1));
+    combined_input
+        .add_linear_exp_val(
+            "example".to_string(),
+            HashMap::from([(z0, 3.0), (z0z1, 1.0)]),
+        )
+        .unwrap();
+    assert_eq!(
+        expectation_values(&combined_input, &known_registers)["example"],
+        -1.5
+    );
+}
+
+/// Available examples, selected by name on the command line.
+///
+/// All examples are run in this order when no name is given.
+///
+const EXAMPLES: &[(&str, fn())] = &[
+    ("intro", intro_main),
+    ("measurement", measurement_main),
+    ("grover", grover_example::grover_main),
+    ("qft", qft_example::qft_main),
+    ("readout_error", readout_error_example::readout_error_main),
+    ("chsh", chsh_example::chsh_main),
+    (
+        "superdense_coding",
+        superdense_coding_example::superdense_main,
+    ),
+    ("vqe_h2", vqe_h2_example::vqe_h2_main),
+    ("qaoa", qaoa_maxcut_example::qaoa_main),
+    (
+        "symbolic_parameters",
+        symbolic_parameters_example::symbolic_parameters_main,
+    ),
+    ("vha", simple_vha_with_roqoqo::run_simple_vha),
+    ("parameter_shift", parameter_shift::parameter_shift_main),
+    (
+        "vha_parameter_scan",
+        simple_vha_with_roqoqo::run_vha_parameter_scan,
+    ),
+    ("vha_gradient_descent", || {
+        simple_vha_with_roqoqo::run_vha_gradient_descent(0.05, 20)
+    }),
+    ("qasm_export", || {
+        qasm_export::qasm_export_main(&entangling_circuit_snippet())
+    }),
+    ("ghz", ghz_example::ghz_main),
+    ("deutsch_jozsa", deutsch_jozsa_example::dj_main),
+    ("decoherence", decoherence_example::decoherence_main),
+    ("ising_vha", ising_vha_example::ising_vha_main),
+    ("phase_estimation", phase_estimation_example::qpe_main),
+    (
+        "serialization_formats",
+        serialization_formats::serialization_formats_main,
+    ),
+    (
+        "mid_circuit_measurement",
+        mid_circuit_measurement_example::mid_circuit_measurement_main,
+    ),
+    ("decomposition", decomposition_example::decomposition_main),
+    (
+        "quantum_classifier",
+        quantum_classifier_example::classifier_main,
+    ),
+    ("qrng", qrng_example::qrng_main),
+    ("trotter", trotter_example::trotter_main),
+    ("initial_state", initial_state_example::initial_state_main),
+    (
+        "amplitude_amplification",
+        amplitude_amplification_example::aa_main,
+    ),
+    ("density_matrix", density_matrix_example::density_main),
+    (
+        "process_fidelity",
+        process_fidelity_example::process_fidelity_main,
+    ),
+    ("metrics", metrics::metrics_main),
+    ("bernstein_vazirani", bernstein_vazirani_example::bv_main),
+    ("loop", loop_example::loop_main),
+    (
+        "parametric_program",
+        parametric_program_example::parametric_program_main,
+    ),
+    (
+        "shot_statistics",
+        shot_statistics_example::shot_statistics_main,
+    ),
+    ("swap_test", swap_test_example::swap_test_main),
+    (
+        "entanglement_entropy",
+        entanglement_entropy_example::entropy_main,
+    ),
+    ("noisy_vha", noisy_vha_example::noisy_vha_main),
+    ("toffoli", toffoli_example::toffoli_main),
+    ("hamiltonian_io", hamiltonian_io::hamiltonian_io_main),
+    ("verification", verification::verification_main),
+];
+
+/// Prints the names of all available examples to stderr.
+fn print_usage(program: &str) {
+    eprintln!("Usage: {} [EXAMPLE]", program);
+    eprintln!("Runs all examples if no EXAMPLE is given. Available examples:");
+    for (name, _) in EXAMPLES.iter() {
+        eprintln!("    {}", name);
+    }
+    eprintln!(
+        "The teleportation example is a separate crate in standalone/3_Teleportation_example."
+    );
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    match args.get(1) {
+        None => {
+            for (_, example) in EXAMPLES.iter() {
+                example();
+            }
+        }
+        Some(name) => match EXAMPLES
+            .iter()
+            .find(|(example_name, _)| example_name == name)
+        {
+            Some((_, example)) => example(),
+            None => {
+                eprintln!("Unknown example: {}", name);
+                print_usage(&args[0]);
+                std::process::exit(1);
+            }
+        },
+    }
+}
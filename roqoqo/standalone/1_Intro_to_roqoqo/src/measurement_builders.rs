@@ -0,0 +1,52 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::measurements::PauliZProductInput;
+use std::collections::HashMap;
+
+// Shortcuts for measurement inputs read out from the register `ro` used throughout the examples.
+// A `PauliZProductInput` first registers the Pauli products measured from a readout register and then
+// defines the expectation values as linear combinations of the registered products by their index.
+// `add_pauli_z_product` does the first step on an existing input, the other builders do both steps for
+// an input with a single observable.
+
+/// Name of the readout register the Pauli products are measured from.
+const READOUT: &str = "ro";
+
+/// Registers the Pauli product Z_q0 Z_q1 ... over all `qubits` in `input` and returns its index.
+///
+/// The index is used to combine the registered products into expectation values with `add_linear_exp_val`.
+///
+pub fn add_pauli_z_product(input: &mut PauliZProductInput, qubits: &[usize]) -> usize {
+    input
+        .add_pauliz_product(READOUT.to_string(), qubits.to_vec())
+        .unwrap()
+}
+
+/// Builds the measurement input for the single expectation value `name` = < Z_qubit >.
+pub fn single_pauli_z(qubit: usize, name: &str) -> PauliZProductInput {
+    pauli_z_product(&[qubit], name, 1.0)
+}
+
+/// Builds the measurement input for the single expectation value `name` = `coefficient` * < Z_q0 Z_q1 ... >.
+///
+/// The product runs over all `qubits`, the input covers the qubits up to the largest one.
+///
+pub fn pauli_z_product(qubits: &[usize], name: &str, coefficient: f64) -> PauliZProductInput {
+    let number_qubits = qubits.iter().max().map_or(0, |qubit| qubit + 1);
+    let mut measurement_input = PauliZProductInput::new(number_qubits, false);
+    let index = add_pauli_z_product(&mut measurement_input, qubits);
+    measurement_input
+        .add_linear_exp_val(name.to_string(), HashMap::from([(index, coefficient)]))
+        .unwrap();
+    measurement_input
+}
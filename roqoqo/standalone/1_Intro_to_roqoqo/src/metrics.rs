@@ -0,0 +1,116 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::qft_example::qft_circuit;
+use crate::simple_vha_with_roqoqo::{vha_ansatz, NUMBER_PARAMETERS};
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::{operations::*, Circuit};
+use std::collections::HashMap;
+
+// Basic statistics of circuits for comparing ansätze. Only gate operations are counted, definitions,
+// measurements and pragmas are ignored.
+// The depth is the number of layers when every gate is placed greedily in the first layer after the
+// last gate acting on one of its qubits. Gates on disjoint qubits can share a layer.
+
+/// Returns true if `operation` is a quantum gate.
+fn is_gate(operation: &Operation) -> bool {
+    operation.tags().contains(&"GateOperation")
+}
+
+/// Returns the number of gates in `circuit` for each gate name.
+pub fn gate_count(circuit: &Circuit) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for operation in circuit.iter().filter(|operation| is_gate(operation)) {
+        *counts.entry(operation.hqslang().to_string()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Returns the number of two-qubit gates in `circuit`.
+pub fn two_qubit_gate_count(circuit: &Circuit) -> usize {
+    circuit
+        .iter()
+        .filter(|operation| operation.tags().contains(&"TwoQubitGateOperation"))
+        .count()
+}
+
+/// Returns the depth of `circuit` from a greedy layering of its gates.
+///
+/// Each gate is placed one layer after the latest layer used by any of its qubits.
+///
+pub fn circuit_depth(circuit: &Circuit) -> usize {
+    let mut last_layer: HashMap<usize, usize> = HashMap::new();
+    let mut depth = 0;
+    for operation in circuit.iter().filter(|operation| is_gate(operation)) {
+        let qubits = match operation.involved_qubits() {
+            InvolvedQubits::Set(qubits) => qubits,
+            _ => continue,
+        };
+        let layer = qubits
+            .iter()
+            .map(|qubit| last_layer.get(qubit).copied().unwrap_or(0))
+            .max()
+            .unwrap_or(0)
+            + 1;
+        for qubit in qubits {
+            last_layer.insert(qubit, layer);
+        }
+        depth = depth.max(layer);
+    }
+    depth
+}
+
+/// Prints the gate counts, the number of two-qubit gates and the depth of `circuit`.
+fn print_metrics(name: &str, circuit: &Circuit) {
+    let mut counts: Vec<(String, usize)> = gate_count(circuit).into_iter().collect();
+    counts.sort();
+    println!("{}:", name);
+    println!("    Gate counts: {:?}", counts);
+    println!("    Two-qubit gates: {}", two_qubit_gate_count(circuit));
+    println!("    Depth: {}", circuit_depth(circuit));
+}
+
+/// Example reporting the metrics of the QFT and VHA ansatz circuits.
+pub fn metrics_main() {
+    println!(">> Circuit metrics example start.");
+    print_metrics("QFT on 3 qubits", &qft_circuit(3));
+    print_metrics("VHA ansatz", &vha_ansatz(&[0.1; NUMBER_PARAMETERS]));
+
+    // Validation check: a chain of CNOTs sharing one qubit with the next has depth 3
+    let mut chain = Circuit::new();
+    chain += CNOT::new(0, 1);
+    chain += CNOT::new(1, 2);
+    chain += CNOT::new(2, 3);
+    assert_eq!(circuit_depth(&chain), 3);
+    assert_eq!(two_qubit_gate_count(&chain), 3);
+
+    // Validation check: gates on disjoint qubits share a layer, non-gate operations are not counted
+    let mut parallel = Circuit::new();
+    parallel += DefinitionBit::new("ro".to_string(), 3, true);
+    parallel += Hadamard::new(0);
+    parallel += Hadamard::new(1);
+    parallel += RotateZ::new(2, CalculatorFloat::from(0.5));
+    parallel += CNOT::new(0, 1);
+    parallel += Hadamard::new(2);
+    parallel += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    assert_eq!(circuit_depth(&parallel), 2);
+    assert_eq!(two_qubit_gate_count(&parallel), 1);
+    assert_eq!(
+        gate_count(&parallel),
+        HashMap::from([
+            ("Hadamard".to_string(), 3),
+            ("RotateZ".to_string(), 1),
+            ("CNOT".to_string(), 1),
+        ])
+    );
+    println!(">> Circuit metrics example end.");
+}
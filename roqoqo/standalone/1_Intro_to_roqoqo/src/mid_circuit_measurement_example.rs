@@ -0,0 +1,70 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+
+// Mid-circuit measurement with feed-forward: a qubit is measured while the circuit is running and a later
+// operation is only applied depending on the measured bit.
+// A `MeasureQubit` writes a single measured bit into a classical register and collapses the qubit, so
+// the remaining circuit continues with the collapsed state. A `PragmaConditional` reads one bit of a register
+// and only runs its sub-circuit if the bit is true.
+// A `PragmaRepeatedMeasurement` would not work here: it is a final measurement that samples many shots at
+// the end of the circuit and its results are not available to operations inside the circuit.
+
+/// Number of simulated shots, each one a separate run of the circuit.
+const NUMBER_SHOTS: usize = 100;
+
+/// Circuit measuring qubit 0 in a superposition and flipping qubit 1 if the outcome is 1.
+///
+/// The mid-circuit outcome is stored in the register `mid`, the final state of qubit 1 in the register `ro`.
+///
+pub fn conditional_flip_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("mid".to_string(), 1, true);
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += Hadamard::new(0);
+    circuit += MeasureQubit::new(0, "mid".to_string(), 0);
+    // The conditional reads the bit written by the MeasureQubit above
+    let mut flip = Circuit::new();
+    flip += PauliX::new(1);
+    circuit += PragmaConditional::new("mid".to_string(), 0, flip);
+    circuit += MeasureQubit::new(1, "ro".to_string(), 0);
+    circuit
+}
+
+/// Example of a mid-circuit measurement conditioning a later gate.
+pub fn mid_circuit_measurement_main() {
+    println!(">> Mid-circuit measurement example start.");
+    let circuit = conditional_flip_circuit();
+    let backend = Backend::new(2);
+    let mut counts = [[0_usize; 2]; 2];
+    for _ in 0..NUMBER_SHOTS {
+        // Each run of a circuit with MeasureQubit operations gives a single shot
+        let (result_bit_registers, _result_float_registers, _result_complex_registers) =
+            backend.run_circuit(&circuit).unwrap();
+        let mid = result_bit_registers["mid"][0][0];
+        let final_bit = result_bit_registers["ro"][0][0];
+        counts[mid as usize][final_bit as usize] += 1;
+    }
+    for (mid, row) in counts.iter().enumerate() {
+        println!(
+            "Mid-circuit outcome {}: qubit 1 measured 0 in {} and 1 in {} shots",
+            mid, row[0], row[1]
+        );
+    }
+
+    // Validation check: qubit 1 always ends up in the state of the mid-circuit outcome
+    assert_eq!(counts[0][1] + counts[1][0], 0);
+    println!(">> Mid-circuit measurement example end.");
+}
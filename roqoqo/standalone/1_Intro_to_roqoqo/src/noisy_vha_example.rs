@@ -0,0 +1,128 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::parameter_shift::expectation_value;
+use crate::simple_vha_with_roqoqo::{
+    compass_search, exact_ground_state_energy, exact_state_energy, vha_ansatz, x_basis_input,
+    x_basis_measurement, z_basis_input, z_basis_measurement, NUMBER_PARAMETERS,
+};
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::{operations::*, Circuit};
+
+// The simple VHA from `simple_vha_with_roqoqo` optimized on a noisy device. After every gate of the trial state
+// preparation a `PragmaDepolarising` acts on each qubit of the gate, so the simulation runs on density matrices.
+// Depolarising noise pulls the state towards the fully mixed state with energy Tr(H)/N = 0, so the noisy
+// energies lie above the ideal ones. The optimizer only sees the noisy energies and in general converges to
+// different parameters: the ideal energy at the noisy optimum shows how far the location of the minimum moved.
+// The optimizer keeps the smallest of many noisy estimates, so all energies are compared exactly at the
+// optimized parameters.
+
+/// Gate time of the depolarising noise added after every gate.
+const GATE_TIME: f64 = 1.0;
+
+/// Depolarising rate of the noisy optimization.
+const DEPOLARISING_RATE: f64 = 0.005;
+
+/// Returns a copy of `circuit` with a `PragmaDepolarising` with the rate `rate` after every gate on each of its qubits.
+fn add_depolarising_noise(circuit: &Circuit, rate: f64) -> Circuit {
+    let mut noisy = Circuit::new();
+    for operation in circuit.iter() {
+        noisy.add_operation(operation.clone());
+        if !operation.tags().contains(&"GateOperation") {
+            continue;
+        }
+        if let InvolvedQubits::Set(qubits) = operation.involved_qubits() {
+            for qubit in qubits {
+                noisy += PragmaDepolarising::new(
+                    qubit,
+                    CalculatorFloat::from(GATE_TIME),
+                    CalculatorFloat::from(rate),
+                );
+            }
+        }
+    }
+    noisy
+}
+
+/// Builds the VHA trial state for `params`, followed by depolarising noise with the rate `rate` if `noise` is set.
+fn trial_state(params: &[f64], noise: Option<f64>) -> Circuit {
+    match noise {
+        Some(rate) => add_depolarising_noise(&vha_ansatz(params), rate),
+        None => vha_ansatz(params),
+    }
+}
+
+/// Measures the energy of the trial state for `params`, optionally with depolarising `noise`.
+fn measured_energy(params: &[f64], noise: Option<f64>) -> f64 {
+    expectation_value(
+        z_basis_measurement(trial_state(params, noise)),
+        &z_basis_input(),
+    ) + expectation_value(
+        x_basis_measurement(trial_state(params, noise)),
+        &x_basis_input(),
+    )
+}
+
+/// Optimizes the VHA parameters with a compass search starting from zero, optionally with depolarising `noise`.
+///
+/// Returns the optimized parameters and the exact energy of the noisy or ideal trial state at these parameters.
+///
+fn optimize_vha(noise: Option<f64>) -> (Vec<f64>, f64) {
+    let (parameters, _measured_energy) = compass_search(
+        |params| measured_energy(params, noise),
+        vec![0.0; NUMBER_PARAMETERS],
+    );
+    let energy = exact_state_energy(&trial_state(&parameters, noise));
+    (parameters, energy)
+}
+
+/// Runs the VHA optimization and returns the exact energy at the optimized parameters.
+///
+/// With `noise` set to `Some(rate)` every gate is followed by depolarising noise with the rate `rate`,
+/// with `None` the ideal backend is used.
+///
+pub fn run_vha(noise: Option<f64>) -> f64 {
+    optimize_vha(noise).1
+}
+
+/// Example comparing the VHA energies optimized with and without depolarising noise.
+pub fn noisy_vha_main() {
+    println!(">> Noisy VHA example start.");
+    let exact_energy = exact_ground_state_energy();
+    let ideal_energy = run_vha(None);
+    let (noisy_parameters, noisy_energy) = optimize_vha(Some(DEPOLARISING_RATE));
+    // Ideal energy of the parameters found by the noisy optimization
+    let ideal_energy_at_noisy_optimum = exact_state_energy(&vha_ansatz(&noisy_parameters));
+
+    println!("Energy of the exact ground state: {:.4}", exact_energy);
+    println!("Converged energy without noise: {:.4}", ideal_energy);
+    println!(
+        "Converged energy with depolarising rate {}: {:.4}",
+        DEPOLARISING_RATE, noisy_energy
+    );
+    println!(
+        "Energy gap attributable to noise: {:.4}",
+        noisy_energy - ideal_energy
+    );
+    println!(
+        "Ideal energy at the parameters of the noisy optimum: {:.4}",
+        ideal_energy_at_noisy_optimum
+    );
+
+    // Validation check: the ideal optimization reaches the ground state up to the optimization accuracy
+    // and stays above it, the noise raises the converged energy
+    assert!(ideal_energy >= exact_energy - 1e-10);
+    assert!(ideal_energy < exact_energy + 0.4);
+    assert!(noisy_energy > ideal_energy);
+    assert!(ideal_energy_at_noisy_optimum >= exact_energy - 1e-10);
+    println!(">> Noisy VHA example end.");
+}
@@ -0,0 +1,90 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::registers::BitOutputRegister;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+// Export of the raw projective measurements in bit registers to CSV files for post-processing,
+// *e.g.* with pandas or a spreadsheet. Each bit of each register becomes one column and each shot one row.
+
+/// Writes the single projective measurements of `registers` to the CSV file at `path`.
+///
+/// The registers are sorted by name and the bits of each register by index, giving the header
+/// `ro_a[0],ro_a[1],ro_b[0],...`. Each following row holds the bits of one shot as 0 or 1.
+/// All registers need to contain the same number of shots.
+///
+pub fn write_bit_registers_csv(
+    path: &Path,
+    registers: &HashMap<String, BitOutputRegister>,
+) -> io::Result<()> {
+    let mut names: Vec<&String> = registers.keys().collect();
+    names.sort();
+    let number_shots = names.first().map_or(0, |name| registers[*name].len());
+    if names
+        .iter()
+        .any(|name| registers[*name].len() != number_shots)
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "All registers need to contain the same number of shots",
+        ));
+    }
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    let mut header: Vec<String> = Vec::new();
+    for name in names.iter() {
+        let length = registers[*name].first().map_or(0, |shot| shot.len());
+        header.extend((0..length).map(|index| format!("{}[{}]", name, index)));
+    }
+    writeln!(writer, "{}", header.join(","))?;
+    let rows = (0..number_shots).map(|shot| {
+        names
+            .iter()
+            .flat_map(|name| registers[*name][shot].iter())
+            .map(|bit| if *bit { "1" } else { "0" })
+            .collect::<Vec<&str>>()
+            .join(",")
+    });
+    for row in rows {
+        writeln!(writer, "{}", row)?;
+    }
+    writer.flush()
+}
+
+/// Example exporting measured bit registers to CSV.
+///
+/// The `registers` are written to `bell_results.csv` in the current directory.
+///
+pub fn output_csv_main(registers: &HashMap<String, BitOutputRegister>) {
+    println!(">> CSV export example start.");
+    write_bit_registers_csv(Path::new("bell_results.csv"), registers).unwrap();
+    println!("Measurements written to bell_results.csv");
+
+    // Validation check: two registers are flattened into one header row and one row per shot
+    let number_shots: usize = 5;
+    let two_registers: HashMap<String, BitOutputRegister> = HashMap::from([
+        ("ro".to_string(), vec![vec![true, false]; number_shots]),
+        ("flag".to_string(), vec![vec![true]; number_shots]),
+    ]);
+    let path = std::env::temp_dir().join("qoqo_examples_registers.csv");
+    write_bit_registers_csv(&path, &two_registers).unwrap();
+    let content = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let lines: Vec<&str> = content.lines().collect();
+    assert_eq!(lines[0], "flag[0],ro[0],ro[1]");
+    assert_eq!(lines.len() - 1, number_shots);
+    assert!(lines[1..].iter().all(|line| *line == "1,1,0"));
+    println!(">> CSV export example end.");
+}
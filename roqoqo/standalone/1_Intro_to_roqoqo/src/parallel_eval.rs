@@ -0,0 +1,47 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::parameter_shift::expectation_value;
+use rayon::prelude::*;
+use roqoqo::measurements::PauliZProductInput;
+use roqoqo::Circuit;
+
+// Scans of a variational parameter evaluate independent circuits, so all grid points can be simulated
+// concurrently. Every task builds its own circuit and its own QuEST backend, no simulator state is shared
+// between threads. The shots of a `PragmaRepeatedMeasurement` are sampled with a thread-local random
+// number generator; circuits with single `MeasureQubit` operations draw from the global random number
+// generator of QuEST and should not be evaluated in parallel.
+
+/// Evaluates the expectation value defined in `input` for every parameter value in `grid` in parallel.
+///
+/// `builder` returns the measurement circuit for one parameter value. The results are in the order of `grid`.
+///
+pub fn eval_parameter_grid(
+    builder: impl Fn(f64) -> Circuit + Sync,
+    input: &PauliZProductInput,
+    grid: &[f64],
+) -> Vec<f64> {
+    grid.par_iter()
+        .map(|parameter| expectation_value(builder(*parameter), input))
+        .collect()
+}
+
+/// Evaluates the expectation value defined in `input` for every parameter value in `grid` one after another.
+pub fn eval_parameter_grid_sequential(
+    builder: impl Fn(f64) -> Circuit,
+    input: &PauliZProductInput,
+    grid: &[f64],
+) -> Vec<f64> {
+    grid.iter()
+        .map(|parameter| expectation_value(builder(*parameter), input))
+        .collect()
+}
@@ -0,0 +1,109 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::measurements::{CheatedPauliZProduct, CheatedPauliZProductInput};
+use roqoqo::{operations::*, Circuit, QuantumProgram};
+use roqoqo_quest::Backend;
+use std::collections::HashMap;
+
+// A `QuantumProgram` bundles a measurement with the names of its free input parameters. The circuits
+// contain the symbolic angles `theta` and `phi`, and `run` substitutes the values given at call time in the
+// order of `input_parameter_names`. The same program, also after serialization, can therefore be evaluated
+// for many parameter values without rebuilding any circuit.
+// The measurement reads the exact expectation values from the simulator with `PragmaGetPauliProduct`,
+// so runs with the same parameters give identical results:
+// < Z0 > + 0.5 < Z0 Z1 > = cos(theta) + 0.5 cos(theta) cos(phi) for RotateY(theta) and RotateY(phi).
+
+/// Builds the program measuring < Z0 > + 0.5 < Z0 Z1 > with the input parameters `theta` and `phi`.
+pub fn parametric_program() -> QuantumProgram {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionFloat::new("z0".to_string(), 1, true);
+    circuit += DefinitionFloat::new("z0z1".to_string(), 1, true);
+    circuit += RotateY::new(0, CalculatorFloat::from("theta"));
+    circuit += RotateY::new(1, CalculatorFloat::from("phi"));
+    circuit +=
+        PragmaGetPauliProduct::new(HashMap::from([(0, 3)]), "z0".to_string(), Circuit::new());
+    circuit += PragmaGetPauliProduct::new(
+        HashMap::from([(0, 3), (1, 3)]),
+        "z0z1".to_string(),
+        Circuit::new(),
+    );
+
+    let mut measurement_input = CheatedPauliZProductInput::new();
+    let z0 = measurement_input.add_pauliz_product("z0".to_string());
+    let z0z1 = measurement_input.add_pauliz_product("z0z1".to_string());
+    measurement_input
+        .add_linear_exp_val(
+            "energy".to_string(),
+            HashMap::from([(z0, 1.0), (z0z1, 0.5)]),
+        )
+        .unwrap();
+
+    QuantumProgram::CheatedPauliZProduct {
+        measurement: CheatedPauliZProduct {
+            constant_circuit: None,
+            circuits: vec![circuit],
+            input: measurement_input,
+        },
+        input_parameter_names: vec!["theta".to_string(), "phi".to_string()],
+    }
+}
+
+/// Runs `program` with the input parameters `theta` and `phi` and returns the measured energy.
+fn run_program(program: &QuantumProgram, parameters: &[f64]) -> f64 {
+    program.run(Backend::new(2), parameters).unwrap().unwrap()["energy"]
+}
+
+/// Example running a parametric program for several input values before and after serialization.
+pub fn parametric_program_main() {
+    println!(">> Parametric program example start.");
+    let program = parametric_program();
+    let inputs = [[0.0, 0.0], [0.5, 1.0], [1.2, -0.4], [3.0, 2.0]];
+    let mut results = Vec::new();
+    for parameters in inputs.iter() {
+        let energy = run_program(&program, parameters);
+        println!(
+            "theta = {:.2}, phi = {:.2}: energy {:.6}",
+            parameters[0], parameters[1], energy
+        );
+
+        // Validation check: the program evaluates the analytic expectation value
+        let expected = parameters[0].cos() + 0.5 * parameters[0].cos() * parameters[1].cos();
+        assert!((energy - expected).abs() < 1e-10);
+        results.push(energy);
+    }
+
+    let program_json = serde_json::to_string(&program).unwrap();
+    let deserialized: QuantumProgram = serde_json::from_str(&program_json).unwrap();
+    println!(
+        "Serialized the program into {} bytes of JSON.",
+        program_json.len()
+    );
+    let fresh = [2.2, 0.3];
+    println!(
+        "Deserialized program with fresh parameters theta = {:.2}, phi = {:.2}: energy {:.6}",
+        fresh[0],
+        fresh[1],
+        run_program(&deserialized, &fresh)
+    );
+
+    // Validation check: the same parameters give the same result across the serialize/deserialize boundary
+    for (parameters, energy) in inputs.iter().zip(results.iter()) {
+        assert_eq!(run_program(&deserialized, parameters), *energy);
+    }
+    assert_eq!(
+        run_program(&deserialized, &fresh),
+        run_program(&program, &fresh)
+    );
+    println!(">> Parametric program example end.");
+}
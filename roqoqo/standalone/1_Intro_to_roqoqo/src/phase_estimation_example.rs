@@ -0,0 +1,102 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::qft_example::inverse_qft_circuit;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+// Quantum phase estimation (QPE) measures the eigenphase phi of a unitary U with U|u> = exp(2 pi i phi) |u>.
+// Here U is the phase gate PhaseShiftState1(2 pi phi) with the eigenstate |1>.
+// Counting qubit k controls U^(2^k), which kicks the phase exp(2 pi i phi 2^k) back onto its |1> component.
+// The counting register then holds the Fourier transform of the integer phi * 2^m, which the inverse QFT
+// turns into a basis state: the measured integer x gives the estimate phi = x / 2^m with m counting qubits.
+
+/// Number of projective measurements of the counting register.
+const NUMBER_MEASUREMENTS: usize = 200;
+
+/// Builds the phase estimation circuit with `counting_qubits` counting qubits for the eigenphase `phase`.
+///
+/// The counting qubits are 0 to m-1, the eigenstate |1> of U is prepared on qubit m. All qubits are read out
+/// into the register `ro`, the last entry of each measurement is the eigenstate qubit.
+///
+pub fn qpe_circuit(counting_qubits: usize, phase: f64) -> Circuit {
+    let target = counting_qubits;
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), counting_qubits + 1, true);
+    circuit += PauliX::new(target);
+    for qubit in 0..counting_qubits {
+        circuit += Hadamard::new(qubit);
+    }
+    // The controlled power U^(2^k) is applied as 2^k repetitions of the controlled U,
+    // as for a general unitary only U itself is available as a gate
+    for qubit in 0..counting_qubits {
+        for _ in 0..2_usize.pow(qubit as u32) {
+            circuit +=
+                ControlledPhaseShift::new(qubit, target, CalculatorFloat::from(2.0 * PI * phase));
+        }
+    }
+    circuit += inverse_qft_circuit(counting_qubits);
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), NUMBER_MEASUREMENTS, None);
+    circuit
+}
+
+/// Runs phase estimation and returns the phase x / 2^m of the most frequently measured integer x.
+pub fn estimate_phase(counting_qubits: usize, phase: f64) -> f64 {
+    let backend = Backend::new(counting_qubits + 1);
+    let (result_bit_registers, _result_float_registers, _result_complex_registers) = backend
+        .run_circuit(&qpe_circuit(counting_qubits, phase))
+        .unwrap();
+    let mut counts: HashMap<usize, usize> = HashMap::new();
+    for measurement in result_bit_registers["ro"].iter() {
+        // Qubit k carries the weight 2^k of the binary fraction x / 2^m
+        let integer: usize = (0..counting_qubits)
+            .filter(|qubit| measurement[*qubit])
+            .map(|qubit| 1 << qubit)
+            .sum();
+        *counts.entry(integer).or_insert(0) += 1;
+    }
+    let (most_frequent, _) = counts.iter().max_by_key(|(_, count)| **count).unwrap();
+    *most_frequent as f64 / 2_f64.powi(counting_qubits as i32)
+}
+
+/// Example estimating eigenphases with quantum phase estimation.
+pub fn qpe_main() {
+    println!(">> Phase estimation example start.");
+    // A phase with an exact binary fraction 0.01 is found with certainty
+    let estimate = estimate_phase(3, 0.25);
+    println!(
+        "True phase 0.25, estimate with 3 counting qubits: {}",
+        estimate
+    );
+
+    // Validation check: the binary fraction of phase 0.25 is estimated exactly
+    assert_eq!(estimate, 0.25);
+
+    // Other phases are approximated by the closest binary fraction, more counting qubits improve the precision
+    let phase = 1.0 / 3.0;
+    for counting_qubits in 2..=6 {
+        let estimate = estimate_phase(counting_qubits, phase);
+        let resolution = 1.0 / 2_f64.powi(counting_qubits as i32);
+        println!(
+            "True phase {:.4}, estimate with {} counting qubits: {:.4} (resolution {:.4})",
+            phase, counting_qubits, estimate, resolution
+        );
+
+        // Validation check: the most likely outcome is the closest binary fraction
+        assert!((estimate - phase).abs() <= resolution / 2.0);
+    }
+    println!(">> Phase estimation example end.");
+}
@@ -0,0 +1,122 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::f64::consts::PI;
+
+// Characterization of a noisy two-qubit gate: a CNOT followed by depolarising noise on both qubits.
+// The average gate fidelity is the fidelity <phi| rho |phi> of the noisy output rho with the ideal output
+// |phi> = CNOT |psi>, averaged over all input states |psi>.
+// Here the average is approximated by a small set of probe states: all 16 products of the four single-qubit
+// states pointing to the corners of a tetrahedron on the Bloch sphere. These four states form a single-qubit
+// 2-design, so the set reproduces the average over random product inputs exactly. An average over all
+// entangled two-qubit inputs as well would need a two-qubit 2-design, *e.g.* the 60 stabilizer states.
+
+/// Polar angle arccos(-1/3) of the three tetrahedron states outside the north pole.
+fn tetrahedron_angle() -> f64 {
+    (-1.0_f64 / 3.0).acos()
+}
+
+/// Circuit preparing the tetrahedron state `index` (0 to 3) on `qubit`.
+fn tetrahedron_state(qubit: usize, index: usize) -> Circuit {
+    let mut circuit = Circuit::new();
+    if index > 0 {
+        circuit += RotateY::new(qubit, CalculatorFloat::from(tetrahedron_angle()));
+        circuit += RotateZ::new(
+            qubit,
+            CalculatorFloat::from(2.0 * PI * (index - 1) as f64 / 3.0),
+        );
+    }
+    circuit
+}
+
+/// Returns the ideal output state vector of the CNOT for the input prepared by `preparation`.
+fn ideal_output(preparation: &Circuit) -> Vec<Complex64> {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionComplex::new("psi".to_string(), 4, true);
+    circuit += preparation.clone();
+    circuit += CNOT::new(0, 1);
+    circuit += PragmaGetStateVector::new("psi".to_string(), None);
+
+    let backend = Backend::new(2);
+    let (_result_bit_registers, _result_float_registers, result_complex_registers) =
+        backend.run_circuit(&circuit).unwrap();
+    result_complex_registers["psi"][0].clone()
+}
+
+/// Returns the flattened output density matrix of the noisy CNOT for the input prepared by `preparation`.
+fn noisy_output(preparation: &Circuit, noise: f64) -> Vec<Complex64> {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionComplex::new("rho".to_string(), 16, true);
+    circuit += preparation.clone();
+    circuit += CNOT::new(0, 1);
+    for qubit in [0, 1] {
+        circuit += PragmaDepolarising::new(
+            qubit,
+            CalculatorFloat::from(1.0),
+            CalculatorFloat::from(noise),
+        );
+    }
+    circuit += PragmaGetDensityMatrix::new("rho".to_string(), None);
+
+    let backend = Backend::new(2);
+    let (_result_bit_registers, _result_float_registers, result_complex_registers) =
+        backend.run_circuit(&circuit).unwrap();
+    result_complex_registers["rho"][0].clone()
+}
+
+/// Estimates the average gate fidelity of the CNOT with depolarising noise of rate `noise` on both qubits.
+///
+/// The fidelity is averaged over the 16 product probe states of the tetrahedron states.
+///
+pub fn average_gate_fidelity(noise: f64) -> f64 {
+    let mut total = 0.0;
+    let mut number_probes = 0;
+    for first in 0..4 {
+        for second in 0..4 {
+            let preparation = tetrahedron_state(0, first) + tetrahedron_state(1, second);
+            let ideal = ideal_output(&preparation);
+            let rho = noisy_output(&preparation, noise);
+            // <phi| rho |phi> with the row-major density matrix
+            let mut fidelity = Complex64::new(0.0, 0.0);
+            for i in 0..4 {
+                for j in 0..4 {
+                    fidelity += ideal[i].conj() * rho[i * 4 + j] * ideal[j];
+                }
+            }
+            total += fidelity.re;
+            number_probes += 1;
+        }
+    }
+    total / number_probes as f64
+}
+
+/// Example printing the average gate fidelity of a noisy CNOT for increasing noise rates.
+pub fn process_fidelity_main() {
+    println!(">> Process fidelity example start.");
+    let mut fidelities = Vec::new();
+    println!("Noise rate | Average gate fidelity");
+    for noise in [0.0, 0.001, 0.01, 0.05, 0.1] {
+        let fidelity = average_gate_fidelity(noise);
+        println!("{:>10.3} | {:.6}", noise, fidelity);
+        fidelities.push(fidelity);
+    }
+
+    // Validation check: the noiseless CNOT is perfect, noise lowers the fidelity
+    assert!((fidelities[0] - 1.0).abs() < 1e-10);
+    assert!(fidelities.windows(2).all(|pair| pair[1] < pair[0]));
+    println!(">> Process fidelity example end.");
+}
@@ -0,0 +1,180 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::measurements::{PauliZProduct, PauliZProductInput};
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::collections::HashMap;
+
+// MaxCut: split the nodes of a graph into two sets so that as many edges as possible connect the two sets.
+// Assigning the set of node i to the qubit value x_i, an edge (i, j) is cut when x_i != x_j, so the cut value is
+// C = sum_(i, j) (1 - Z_i Z_j) / 2.
+// The quantum approximate optimization algorithm (QAOA) alternates the time evolution under C (cost layer)
+// with rotations around X (mixer layer) and optimizes the evolution angles to maximize <C>.
+
+/// Number of projective measurements used to estimate the expected cut value.
+const NUMBER_MEASUREMENTS: usize = 2000;
+
+/// Returns the number of qubits needed to represent all nodes of the graph given by `edges`.
+fn number_nodes(edges: &[(usize, usize)]) -> usize {
+    edges.iter().map(|(i, j)| i.max(j) + 1).max().unwrap_or(0)
+}
+
+/// Measurement input of the cut value C = sum_(i, j) (1 - Z_i Z_j) / 2 for the graph given by `edges`.
+///
+/// The constant part is added as the Pauli product with an empty mask, which always evaluates to one.
+///
+pub fn cost_hamiltonian(edges: &[(usize, usize)]) -> PauliZProductInput {
+    let mut measurement_input = PauliZProductInput::new(number_nodes(edges), false);
+    let mut linear: HashMap<usize, f64> = HashMap::new();
+    let identity = measurement_input
+        .add_pauliz_product("ro".to_string(), vec![])
+        .unwrap();
+    linear.insert(identity, 0.5 * edges.len() as f64);
+    for (i, j) in edges {
+        let index = measurement_input
+            .add_pauliz_product("ro".to_string(), vec![*i, *j])
+            .unwrap();
+        *linear.entry(index).or_insert(0.0) -= 0.5;
+    }
+    measurement_input
+        .add_linear_exp_val("cut".to_string(), linear)
+        .unwrap();
+    measurement_input
+}
+
+/// Builds the QAOA circuit with one layer for each entry of `gammas` and `betas`.
+///
+/// The cost layer applies exp(-i gamma C), which is up to a global phase a product of exp(i gamma/2 Z_i Z_j)
+/// over all edges. Each ZZ-interaction is implemented with a `RotateZ` between two CNOT gates.
+/// The mixer layer applies exp(-i beta X) with a `RotateX` on each qubit.
+///
+pub fn qaoa_circuit(gammas: &[f64], betas: &[f64], edges: &[(usize, usize)]) -> Circuit {
+    assert_eq!(
+        gammas.len(),
+        betas.len(),
+        "Need one gamma and beta per layer"
+    );
+    let number_qubits = number_nodes(edges);
+    let mut circuit = Circuit::new();
+    for qubit in 0..number_qubits {
+        circuit += Hadamard::new(qubit);
+    }
+    for (gamma, beta) in gammas.iter().zip(betas.iter()) {
+        for (i, j) in edges {
+            circuit += CNOT::new(*i, *j);
+            circuit += RotateZ::new(*j, CalculatorFloat::from(-gamma));
+            circuit += CNOT::new(*i, *j);
+        }
+        for qubit in 0..number_qubits {
+            circuit += RotateX::new(qubit, CalculatorFloat::from(2.0 * beta));
+        }
+    }
+    circuit
+}
+
+/// Estimates the expected cut value of the QAOA state for the given angles.
+pub fn expected_cut(gammas: &[f64], betas: &[f64], edges: &[(usize, usize)]) -> f64 {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), number_nodes(edges), true);
+    circuit += qaoa_circuit(gammas, betas, edges);
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), NUMBER_MEASUREMENTS, None);
+
+    let measurement = PauliZProduct {
+        input: cost_hamiltonian(edges),
+        circuits: vec![circuit],
+        constant_circuit: None,
+    };
+    let backend = Backend::new(number_nodes(edges));
+    backend.run_measurement(&measurement).unwrap().unwrap()["cut"]
+}
+
+/// Number of edges of the graph cut by the assignment `bits`.
+pub fn cut_value(bits: &[bool], edges: &[(usize, usize)]) -> usize {
+    edges.iter().filter(|(i, j)| bits[*i] != bits[*j]).count()
+}
+
+/// Optimizes the QAOA angles for `number_layers` layers with a simple compass search.
+///
+/// Each angle is moved up and down by the current step size and the change is kept if the expected cut
+/// increases. When no move improves the cut the step size is halved.
+/// Returns the optimized gammas and betas.
+///
+pub fn optimize_angles(number_layers: usize, edges: &[(usize, usize)]) -> (Vec<f64>, Vec<f64>) {
+    let mut parameters = vec![0.5; 2 * number_layers];
+    let evaluate = |parameters: &[f64]| {
+        let (gammas, betas) = parameters.split_at(number_layers);
+        expected_cut(gammas, betas, edges)
+    };
+    let mut best = evaluate(&parameters);
+    let mut step = 0.4;
+    while step > 0.02 {
+        let mut improved = false;
+        for index in 0..parameters.len() {
+            for direction in [1.0, -1.0] {
+                let mut trial = parameters.clone();
+                trial[index] += direction * step;
+                let value = evaluate(&trial);
+                if value > best {
+                    best = value;
+                    parameters = trial;
+                    improved = true;
+                }
+            }
+        }
+        if !improved {
+            step /= 2.0;
+        }
+    }
+    let betas = parameters.split_off(number_layers);
+    (parameters, betas)
+}
+
+/// Example solving MaxCut on a ring of four nodes with QAOA.
+pub fn qaoa_main() {
+    println!(">> QAOA MaxCut example start.");
+    let edges: Vec<(usize, usize)> = vec![(0, 1), (1, 2), (2, 3), (3, 0)];
+    let number_layers: usize = 2;
+    let number_qubits = number_nodes(&edges);
+
+    let (gammas, betas) = optimize_angles(number_layers, &edges);
+    println!("Optimized gammas: {:?}", gammas);
+    println!("Optimized betas: {:?}", betas);
+    println!(
+        "Expected cut value: {:.4}",
+        expected_cut(&gammas, &betas, &edges)
+    );
+
+    // Sample bitstrings from the optimized state and keep the best cut
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), number_qubits, true);
+    circuit += qaoa_circuit(&gammas, &betas, &edges);
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 100, None);
+    let backend = Backend::new(number_qubits);
+    let (result_bit_registers, _result_float_registers, _result_complex_registers) =
+        backend.run_circuit(&circuit).unwrap();
+    let best_bitstring = result_bit_registers["ro"]
+        .iter()
+        .max_by_key(|bits| cut_value(bits, &edges))
+        .unwrap();
+    let best_cut = cut_value(best_bitstring, &edges);
+    println!(
+        "Best sampled bitstring: {:?} with cut value {}",
+        best_bitstring, best_cut
+    );
+
+    // Validation check: the ring of four nodes is fully cut by alternating the sets
+    assert_eq!(best_cut, edges.len());
+    println!(">> QAOA MaxCut example end.");
+}
@@ -0,0 +1,137 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::{operations::*, Circuit};
+
+// OpenQASM 2.0 is a plain-text circuit format understood by many other toolchains (e.g. Qiskit) and
+// hardware providers. This module exports the subset of roqoqo operations used in these examples.
+// All qubits are collected in a single quantum register `q`, every `DefinitionBit` becomes a classical register.
+
+/// Returns the float value of a rotation angle or an error for symbolic parameters.
+fn angle_to_qasm(theta: &CalculatorFloat) -> Result<String, String> {
+    theta
+        .float()
+        .map(|value| format!("{}", value))
+        .map_err(|_| {
+            format!(
+                "Symbolic parameter {} needs to be substituted before exporting to OpenQASM 2.0",
+                theta
+            )
+        })
+}
+
+/// Exports `circuit` to an OpenQASM 2.0 program.
+///
+/// Supported operations are `DefinitionBit`, `Hadamard`, `CNOT`, `RotateX`, `RotateY`, `RotateZ`,
+/// `PauliX`, `PauliY`, `PauliZ` and `MeasureQubit`.
+/// Returns an error naming the operation if the circuit contains any other operation.
+/// The quantum register `q` is omitted if no operation acts on a qubit.
+///
+pub fn circuit_to_qasm2(circuit: &Circuit) -> Result<String, String> {
+    let mut number_qubits: usize = 0;
+    for operation in circuit.iter() {
+        if let InvolvedQubits::Set(qubits) = operation.involved_qubits() {
+            if let Some(max_qubit) = qubits.iter().max() {
+                number_qubits = number_qubits.max(max_qubit + 1);
+            }
+        }
+    }
+
+    let mut lines: Vec<String> = vec![
+        "OPENQASM 2.0;".to_string(),
+        "include \"qelib1.inc\";".to_string(),
+    ];
+    // OpenQASM 2.0 does not allow registers of size zero
+    if number_qubits > 0 {
+        lines.push(format!("qreg q[{}];", number_qubits));
+    }
+    for operation in circuit.iter() {
+        let line = match operation {
+            Operation::DefinitionBit(op) => format!("creg {}[{}];", op.name(), op.length()),
+            Operation::Hadamard(op) => format!("h q[{}];", op.qubit()),
+            Operation::CNOT(op) => format!("cx q[{}],q[{}];", op.control(), op.target()),
+            Operation::RotateX(op) => {
+                format!("rx({}) q[{}];", angle_to_qasm(op.theta())?, op.qubit())
+            }
+            Operation::RotateY(op) => {
+                format!("ry({}) q[{}];", angle_to_qasm(op.theta())?, op.qubit())
+            }
+            Operation::RotateZ(op) => {
+                format!("rz({}) q[{}];", angle_to_qasm(op.theta())?, op.qubit())
+            }
+            Operation::PauliX(op) => format!("x q[{}];", op.qubit()),
+            Operation::PauliY(op) => format!("y q[{}];", op.qubit()),
+            Operation::PauliZ(op) => format!("z q[{}];", op.qubit()),
+            Operation::MeasureQubit(op) => format!(
+                "measure q[{}] -> {}[{}];",
+                op.qubit(),
+                op.readout(),
+                op.readout_index()
+            ),
+            _ => {
+                return Err(format!(
+                    "Operation {} is not supported by the OpenQASM 2.0 export",
+                    operation.hqslang()
+                ))
+            }
+        };
+        lines.push(line);
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Example exporting circuits to OpenQASM 2.0.
+pub fn qasm_export_main(circuit: &Circuit) {
+    println!(">> OpenQASM export example start.");
+    print!("{}", circuit_to_qasm2(circuit).unwrap());
+
+    // Validation check: a small circuit with a measurement is exported line by line.
+    // Only the export direction is checked, the examples do not parse OpenQASM back into a circuit
+    let mut measured_circuit = Circuit::new();
+    measured_circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    measured_circuit += Hadamard::new(0);
+    measured_circuit += CNOT::new(0, 1);
+    measured_circuit += RotateZ::new(1, CalculatorFloat::from(0.5));
+    measured_circuit += MeasureQubit::new(0, "ro".to_string(), 0);
+    measured_circuit += MeasureQubit::new(1, "ro".to_string(), 1);
+    let qasm = circuit_to_qasm2(&measured_circuit).unwrap();
+    let expected = [
+        "OPENQASM 2.0;",
+        "include \"qelib1.inc\";",
+        "qreg q[2];",
+        "creg ro[2];",
+        "h q[0];",
+        "cx q[0],q[1];",
+        "rz(0.5) q[1];",
+        "measure q[0] -> ro[0];",
+        "measure q[1] -> ro[1];",
+    ];
+    assert_eq!(qasm.lines().collect::<Vec<&str>>(), expected);
+
+    // Validation check: unsupported operations give an error instead of a panic
+    let mut unsupported = Circuit::new();
+    unsupported += PragmaRepeatedMeasurement::new("ro".to_string(), 10, None);
+    let error = circuit_to_qasm2(&unsupported).unwrap_err();
+    println!("Exporting an unsupported operation: {}", error);
+    assert!(error.contains("PragmaRepeatedMeasurement"));
+
+    // Validation check: a circuit without qubit operations declares no empty quantum register
+    let mut classical_only = Circuit::new();
+    classical_only += DefinitionBit::new("ro".to_string(), 1, true);
+    let qasm = circuit_to_qasm2(&classical_only).unwrap();
+    assert_eq!(
+        qasm.lines().collect::<Vec<&str>>(),
+        ["OPENQASM 2.0;", "include \"qelib1.inc\";", "creg ro[1];"]
+    );
+    println!(">> OpenQASM export example end.");
+}
@@ -0,0 +1,159 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::verification::{assert_circuit_unitary, statevectors_equal_up_to_phase};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::f64::consts::PI;
+
+// The quantum Fourier transform (QFT) maps a basis state |x> of n qubits onto
+// 1/sqrt(N) * sum_k exp(2 pi i x k / N) |k> with N = 2^n.
+// In roqoqo qubit 0 is the least significant bit of the basis state index, so the most significant
+// qubit n-1 is transformed first.
+
+/// Builds the quantum Fourier transform on `num_qubits` qubits.
+///
+/// The QFT is constructed from Hadamard gates and controlled phase shifts followed by SWAP gates
+/// reversing the order of the qubits.
+///
+pub fn qft_circuit(num_qubits: usize) -> Circuit {
+    let mut circuit = Circuit::new();
+    for target in (0..num_qubits).rev() {
+        circuit += Hadamard::new(target);
+        for control in (0..target).rev() {
+            let angle = PI / 2_f64.powi((target - control) as i32);
+            circuit += ControlledPhaseShift::new(control, target, CalculatorFloat::from(angle));
+        }
+    }
+    // The Hadamard and phase layers write the Fourier coefficients in bit-reversed order:
+    // the most significant qubit ends up holding the least significant frequency bit.
+    // Without the SWAP gates the amplitude at index k would belong to the reversed bitstring of k.
+    for qubit in 0..num_qubits / 2 {
+        circuit += SWAP::new(qubit, num_qubits - 1 - qubit);
+    }
+    circuit
+}
+
+/// Builds the inverse quantum Fourier transform on `num_qubits` qubits.
+///
+/// The inverse applies the operations of [qft_circuit] in reverse order with negated phases.
+///
+pub fn inverse_qft_circuit(num_qubits: usize) -> Circuit {
+    let mut circuit = Circuit::new();
+    for qubit in 0..num_qubits / 2 {
+        circuit += SWAP::new(qubit, num_qubits - 1 - qubit);
+    }
+    for target in 0..num_qubits {
+        for control in 0..target {
+            let angle = -PI / 2_f64.powi((target - control) as i32);
+            circuit += ControlledPhaseShift::new(control, target, CalculatorFloat::from(angle));
+        }
+        circuit += Hadamard::new(target);
+    }
+    circuit
+}
+
+/// Prepares the basis state with index `basis_state` on `num_qubits` qubits using `PauliX` gates.
+fn basis_state_preparation(num_qubits: usize, basis_state: usize) -> Circuit {
+    let mut circuit = Circuit::new();
+    for qubit in 0..num_qubits {
+        if basis_state & (1 << qubit) != 0 {
+            circuit += PauliX::new(qubit);
+        }
+    }
+    circuit
+}
+
+/// Runs `circuit` on `num_qubits` qubits and returns the final state vector.
+fn run_state_vector(num_qubits: usize, circuit: &Circuit) -> Vec<Complex64> {
+    let mut readout = Circuit::new();
+    readout += DefinitionComplex::new("psi".to_string(), 2_usize.pow(num_qubits as u32), true);
+    readout += circuit.clone();
+    readout += PragmaGetStateVector::new("psi".to_string(), None);
+
+    let backend = Backend::new(num_qubits);
+    let (_result_bit_registers, _result_float_registers, result_complex_registers) =
+        backend.run_circuit(&readout).unwrap();
+    result_complex_registers["psi"][0].clone()
+}
+
+/// Example applying the quantum Fourier transform to a computational basis state.
+///
+/// All amplitudes of the transformed state have the magnitude 1/sqrt(N),
+/// the phase of the amplitude at index k is 2 pi x k / N for the input basis state x.
+///
+pub fn qft_main() {
+    println!(">> Quantum Fourier transform example start.");
+    let number_qubits: usize = 3;
+    let basis_state: usize = 5;
+    let dimension = 2_usize.pow(number_qubits as u32);
+
+    let circuit = basis_state_preparation(number_qubits, basis_state) + qft_circuit(number_qubits);
+    println!("QFT circuit: {}", circuit);
+
+    let amplitudes = run_state_vector(number_qubits, &circuit);
+    println!("Amplitudes after the QFT of |{}>:", basis_state);
+    for (index, amplitude) in amplitudes.iter().enumerate() {
+        println!(
+            "{}: magnitude {:.4}, phase {:.4} * pi",
+            index,
+            amplitude.norm(),
+            amplitude.arg() / PI
+        );
+    }
+
+    // Validation check: compare with the analytic Fourier coefficients
+    let expected: Vec<Complex64> = (0..dimension)
+        .map(|index| {
+            Complex64::from_polar(
+                1.0 / (dimension as f64).sqrt(),
+                2.0 * PI * (basis_state * index) as f64 / dimension as f64,
+            )
+        })
+        .collect();
+    assert!(statevectors_equal_up_to_phase(
+        &amplitudes,
+        &expected,
+        1e-10
+    ));
+
+    // Validation check: the inverse QFT undoes the QFT for every basis state
+    for state in 0..dimension {
+        let roundtrip = basis_state_preparation(number_qubits, state)
+            + qft_circuit(number_qubits)
+            + inverse_qft_circuit(number_qubits);
+        let amplitudes = run_state_vector(number_qubits, &roundtrip);
+        let expected: Vec<Complex64> = (0..dimension)
+            .map(|index| Complex64::new(if index == state { 1.0 } else { 0.0 }, 0.0))
+            .collect();
+        assert!(statevectors_equal_up_to_phase(
+            &amplitudes,
+            &expected,
+            1e-10
+        ));
+    }
+    println!("Inverse QFT recovers all basis states.");
+
+    // Validation check: the QFT circuit is unitary, while resetting a qubit breaks unitarity.
+    // The reset maps every column onto a state with qubit 0 in |0>, so the deviation does not depend
+    // on random measurement outcomes
+    assert_circuit_unitary(&qft_circuit(number_qubits), number_qubits).unwrap();
+    let mut reset = qft_circuit(number_qubits);
+    reset += PragmaActiveReset::new(0);
+    let error = assert_circuit_unitary(&reset, number_qubits).unwrap_err();
+    println!("Unitarity check after a reset: {}", error);
+    assert!(error.contains("Frobenius norm of U^dagger U - I"));
+    println!(">> Quantum Fourier transform example end.");
+}
@@ -0,0 +1,75 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+
+// A quantum random number generator: a qubit in the superposition (|0> + |1>) / sqrt(2) created by a
+// Hadamard gate gives 0 or 1 with probability 1/2 each when it is measured.
+// The simulator needs memory exponential in the number of qubits, so the bits are produced in chunks
+// of at most `CHUNK_QUBITS` qubits, each chunk measured once.
+// On a simulator the randomness of course comes from a classical pseudo random number generator.
+
+/// Maximal number of qubits simulated at once.
+const CHUNK_QUBITS: usize = 16;
+
+/// Number of bits generated for the statistical check.
+const NUMBER_TEST_BITS: usize = 10000;
+
+/// Returns `num_bits` random bits from measuring qubits in superposition once.
+pub fn random_bits(num_bits: usize) -> Vec<bool> {
+    let mut bits = Vec::with_capacity(num_bits);
+    while bits.len() < num_bits {
+        let chunk = CHUNK_QUBITS.min(num_bits - bits.len());
+        let mut circuit = Circuit::new();
+        circuit += DefinitionBit::new("ro".to_string(), chunk, true);
+        for qubit in 0..chunk {
+            circuit += Hadamard::new(qubit);
+        }
+        circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 1, None);
+
+        let backend = Backend::new(chunk);
+        let (result_bit_registers, _result_float_registers, _result_complex_registers) =
+            backend.run_circuit(&circuit).unwrap();
+        bits.extend_from_slice(&result_bit_registers["ro"][0]);
+    }
+    bits
+}
+
+/// Returns a random 64-bit number, with bit i of the number given by the i-th random bit.
+pub fn random_u64() -> u64 {
+    random_bits(64)
+        .iter()
+        .enumerate()
+        .fold(0, |number, (index, bit)| number | ((*bit as u64) << index))
+}
+
+/// Example generating random numbers from measurements of qubits in superposition.
+pub fn qrng_main() {
+    println!(">> Quantum random number generator example start.");
+    for _ in 0..5 {
+        println!("Random number: {}", random_u64());
+    }
+
+    // Validation check: the fraction of ones in a large batch is close to 1/2
+    let bits = random_bits(NUMBER_TEST_BITS);
+    assert_eq!(bits.len(), NUMBER_TEST_BITS);
+    let fraction_ones = bits.iter().filter(|bit| **bit).count() as f64 / NUMBER_TEST_BITS as f64;
+    println!(
+        "Fraction of ones in {} random bits: {:.4}",
+        NUMBER_TEST_BITS, fraction_ones
+    );
+    // Five standard deviations of the binomial distribution
+    assert!((fraction_ones - 0.5).abs() < 5.0 * 0.5 / (NUMBER_TEST_BITS as f64).sqrt());
+    println!(">> Quantum random number generator example end.");
+}
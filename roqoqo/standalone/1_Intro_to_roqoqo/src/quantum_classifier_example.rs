@@ -0,0 +1,122 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::parameter_shift::{expectation_value, gradient};
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::measurements::PauliZProductInput;
+use roqoqo::{operations::*, Circuit};
+use std::collections::HashMap;
+
+// A variational quantum classifier on a single qubit. The classical feature x is encoded in the state
+// RotateY(x) |0>, a trainable layer RotateX(w_0) RotateY(w_1) follows, and the expectation value < Z > in [-1, 1]
+// is the class score: a positive score predicts the class +1, a negative score the class -1.
+// The weights are trained with gradient descent on the squared error between scores and labels,
+// with the gradients of the scores measured by the parameter-shift rule.
+
+/// Number of projective measurements per score evaluation.
+const NUMBER_MEASUREMENTS: usize = 1000;
+
+/// Labelled training data (feature, class): small angles belong to class -1, large angles to class +1.
+const TRAINING_DATA: [(f64, f64); 6] = [
+    (0.2, -1.0),
+    (0.6, -1.0),
+    (1.0, -1.0),
+    (2.0, 1.0),
+    (2.4, 1.0),
+    (2.8, 1.0),
+];
+
+/// Measurement circuit encoding `feature` followed by the trainable layer with the `weights`.
+fn classifier_circuit(feature: f64, weights: &[f64]) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += RotateY::new(0, CalculatorFloat::from(feature));
+    circuit += RotateX::new(0, CalculatorFloat::from(weights[0]));
+    circuit += RotateY::new(0, CalculatorFloat::from(weights[1]));
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), NUMBER_MEASUREMENTS, None);
+    circuit
+}
+
+/// Measurement input of the class score < Z >.
+fn score_input() -> PauliZProductInput {
+    let mut measurement_input = PauliZProductInput::new(1, false);
+    let z0 = measurement_input
+        .add_pauliz_product("ro".to_string(), vec![0])
+        .unwrap();
+    measurement_input
+        .add_linear_exp_val("score".to_string(), HashMap::from([(z0, 1.0)]))
+        .unwrap();
+    measurement_input
+}
+
+/// Returns the class score < Z > of `feature` for the trainable `weights`.
+pub fn classify(feature: f64, weights: &[f64]) -> f64 {
+    expectation_value(classifier_circuit(feature, weights), &score_input())
+}
+
+/// Returns the fraction of training points whose predicted class matches the label.
+fn accuracy(weights: &[f64]) -> f64 {
+    let correct = TRAINING_DATA
+        .iter()
+        .filter(|(feature, label)| classify(*feature, weights).signum() == *label)
+        .count();
+    correct as f64 / TRAINING_DATA.len() as f64
+}
+
+/// Trains the weights with `iterations` gradient descent steps on the squared error of the training data.
+///
+/// The gradient of (score - label)^2 is 2 (score - label) times the parameter-shift gradient of the score.
+///
+pub fn train(initial: Vec<f64>, learning_rate: f64, iterations: usize) -> Vec<f64> {
+    let input = score_input();
+    let mut weights = initial;
+    for _ in 0..iterations {
+        let mut loss_gradient = vec![0.0; weights.len()];
+        for (feature, label) in TRAINING_DATA.iter() {
+            let score = classify(*feature, &weights);
+            let builder = |params: &[f64]| classifier_circuit(*feature, params);
+            for (total, derivative) in loss_gradient
+                .iter_mut()
+                .zip(gradient(builder, &input, &weights))
+            {
+                *total += 2.0 * (score - label) * derivative;
+            }
+        }
+        for (weight, derivative) in weights.iter_mut().zip(loss_gradient.iter()) {
+            *weight -= learning_rate * derivative / TRAINING_DATA.len() as f64;
+        }
+    }
+    weights
+}
+
+/// Example training the single-qubit classifier on a small inline data set.
+pub fn classifier_main() {
+    println!(">> Quantum classifier example start.");
+    let initial = vec![0.0, 0.0];
+    let accuracy_before = accuracy(&initial);
+    let weights = train(initial, 0.5, 20);
+    let accuracy_after = accuracy(&weights);
+    println!("Trained weights: {:?}", weights);
+    println!(
+        "Training accuracy before optimization: {:.2}",
+        accuracy_before
+    );
+    println!(
+        "Training accuracy after optimization: {:.2}",
+        accuracy_after
+    );
+
+    // Validation check: training separates the two classes
+    assert!(accuracy_after > accuracy_before);
+    assert_eq!(accuracy_after, 1.0);
+    println!(">> Quantum classifier example end.");
+}
@@ -0,0 +1,119 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ndarray::array;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+
+// Real devices do not read out qubits perfectly: with some probability a measured 0 is reported as 1
+// and vice versa. This example models such a readout error as a bit-flip noise channel applied right
+// before the measurement and shows how the known flip probability can be used to correct the
+// measured distribution.
+
+/// Adds a bit-flip error with probability `p_flip` on `qubit` to the end of `circuit`.
+///
+/// The bit flip rho -> (1 - p) rho + p X rho X is the Lindblad evolution with the jump operator X = σ+ + σ-
+/// and rate γ for the time t, with p = (1 - exp(-2 γ t)) / 2. In the basis (σ+, σ-, σz) of
+/// `PragmaGeneralNoise` the jump operator X corresponds to the rates [[γ, γ, 0], [γ, γ, 0], [0, 0, 0]].
+///
+pub fn apply_readout_error(circuit: &mut Circuit, qubit: usize, p_flip: f64) {
+    assert!(
+        (0.0..0.5).contains(&p_flip),
+        "Flip probability needs to be in [0, 0.5)"
+    );
+    let gate_time = 1.0;
+    let rate = -(1.0 - 2.0 * p_flip).ln() / (2.0 * gate_time);
+    let rates = array![[rate, rate, 0.0], [rate, rate, 0.0], [0.0, 0.0, 0.0]];
+    circuit.add_operation(PragmaGeneralNoise::new(
+        qubit,
+        CalculatorFloat::from(gate_time),
+        rates,
+    ));
+}
+
+/// Corrects a measured distribution for independent bit flips with probability `p_flip` on each qubit.
+///
+/// The measured distribution is the true distribution multiplied with the confusion matrix
+/// C ⊗ ... ⊗ C with C = [[1 - p, p], [p, 1 - p]] for each qubit. The inverse of the confusion matrix
+/// is applied one qubit at a time to all pairs of basis states that only differ in that qubit.
+/// Note that this naive inversion can produce small negative probabilities because of shot noise.
+///
+pub fn invert_confusion_matrix(distribution: &[f64], p_flip: f64) -> Vec<f64> {
+    let mut corrected = distribution.to_vec();
+    let number_qubits = distribution.len().trailing_zeros() as usize;
+    let norm = 1.0 / (1.0 - 2.0 * p_flip);
+    for qubit in 0..number_qubits {
+        for index in 0..distribution.len() {
+            if index & (1 << qubit) == 0 {
+                let partner = index | (1 << qubit);
+                let (zero, one) = (corrected[index], corrected[partner]);
+                corrected[index] = norm * ((1.0 - p_flip) * zero - p_flip * one);
+                corrected[partner] = norm * ((1.0 - p_flip) * one - p_flip * zero);
+            }
+        }
+    }
+    corrected
+}
+
+/// Example measuring a Bell state with readout errors.
+///
+/// The raw measured distribution is printed next to the ideal distribution of the Bell state,
+/// followed by the distribution after correcting with the inverted confusion matrix.
+///
+pub fn readout_error_main() {
+    println!(">> Readout error example start.");
+    let number_qubits: usize = 2;
+    let number_measurements: usize = 10000;
+    let p_flip = 0.05;
+
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), number_qubits, true);
+    circuit += Hadamard::new(0);
+    circuit += CNOT::new(0, 1);
+    for qubit in 0..number_qubits {
+        apply_readout_error(&mut circuit, qubit, p_flip);
+    }
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), number_measurements, None);
+
+    let backend = Backend::new(number_qubits);
+    let (result_bit_registers, _result_float_registers, _result_complex_registers) =
+        backend.run_circuit(&circuit).unwrap();
+
+    // Basis state index: qubit 0 is the least significant bit
+    let mut measured = vec![0.0; 2_usize.pow(number_qubits as u32)];
+    for measurement in result_bit_registers["ro"].iter() {
+        let index: usize = (0..number_qubits)
+            .filter(|qubit| measurement[*qubit])
+            .map(|qubit| 1 << qubit)
+            .sum();
+        measured[index] += 1.0 / number_measurements as f64;
+    }
+    let ideal = [0.5, 0.0, 0.0, 0.5];
+    let corrected = invert_confusion_matrix(&measured, p_flip);
+
+    println!("Flip probability per qubit: {}", p_flip);
+    println!("state: ideal, measured, corrected");
+    for index in 0..ideal.len() {
+        println!(
+            "|{:02b}>: {:.4}, {:.4}, {:.4}",
+            index, ideal[index], measured[index], corrected[index]
+        );
+    }
+
+    // Validation check: the corrected distribution is close to the ideal one
+    for (corrected_value, ideal_value) in corrected.iter().zip(ideal.iter()) {
+        assert!((corrected_value - ideal_value).abs() < 0.05);
+    }
+    println!(">> Readout error example end.");
+}
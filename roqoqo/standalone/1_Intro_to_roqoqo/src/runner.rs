@@ -0,0 +1,65 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::registers::BitOutputRegister;
+use roqoqo::Circuit;
+use std::collections::HashMap;
+
+// Examples taking a `&dyn CircuitRunner` only depend on the measured bits and not on a specific simulator.
+// `QuestRunner` runs circuits on the roqoqo-quest simulator, any other backend can be used by implementing
+// the trait. `MockRunner` does not simulate anything and returns fixed results, which makes the processing
+// of measurement results reproducible.
+
+/// Measured bit registers of a circuit run, indexed by register name.
+pub type BitRegisters = HashMap<String, BitOutputRegister>;
+
+/// Runs a circuit and returns the measured bit registers.
+pub trait CircuitRunner {
+    /// Runs `circuit` and returns its bit registers or a description of the failure.
+    fn run(&self, circuit: &Circuit) -> Result<BitRegisters, String>;
+}
+
+/// Runner simulating circuits with the roqoqo-quest backend.
+pub struct QuestRunner {
+    /// Number of simulated qubits.
+    pub number_qubits: usize,
+}
+
+impl QuestRunner {
+    /// Creates a runner simulating `number_qubits` qubits.
+    pub fn new(number_qubits: usize) -> Self {
+        QuestRunner { number_qubits }
+    }
+}
+
+impl CircuitRunner for QuestRunner {
+    fn run(&self, circuit: &Circuit) -> Result<BitRegisters, String> {
+        let backend = roqoqo_quest::Backend::new(self.number_qubits);
+        let (result_bit_registers, _result_float_registers, _result_complex_registers) = backend
+            .run_circuit(circuit)
+            .map_err(|error| format!("Running the circuit on QuEST failed: {}", error))?;
+        Ok(result_bit_registers)
+    }
+}
+
+/// Runner returning the same canned bit registers for every circuit.
+pub struct MockRunner {
+    /// Bit registers returned by every run.
+    pub registers: BitRegisters,
+}
+
+impl CircuitRunner for MockRunner {
+    fn run(&self, _circuit: &Circuit) -> Result<BitRegisters, String> {
+        Ok(self.registers.clone())
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::vqe_h2_example::{h2_ansatz, h2_hamiltonian};
+use roqoqo::measurements::PauliZProduct;
+use roqoqo::{operations::*, Circuit, QuantumProgram};
+
+// All roqoqo objects implement serde serialization, so any serde format can store them.
+// JSON is human-readable and understood by virtually every language, which makes it the natural choice
+// for exchanging programs and inspecting them by hand. Bincode is a compact binary format: smaller and
+// faster to read and write, but not readable and only portable to other serde/bincode users.
+
+/// Serializes `program` to a JSON string.
+pub fn serialize_program_json(program: &QuantumProgram) -> String {
+    serde_json::to_string(program).unwrap()
+}
+
+/// Serializes `program` to bincode bytes, using the same legacy bincode configuration as roqoqo.
+pub fn serialize_program_bincode(program: &QuantumProgram) -> Vec<u8> {
+    bincode::serde::encode_to_vec(program, bincode::config::legacy()).unwrap()
+}
+
+/// Builds the quantum program measuring the energy of the H2 molecule from the VQE example.
+fn h2_program() -> QuantumProgram {
+    let mut z_basis = Circuit::new();
+    z_basis += DefinitionBit::new("ro_z".to_string(), 2, true);
+    z_basis += h2_ansatz(0.2);
+    z_basis += PragmaRepeatedMeasurement::new("ro_z".to_string(), 1000, None);
+
+    let mut x_basis = Circuit::new();
+    x_basis += DefinitionBit::new("ro_x".to_string(), 2, true);
+    x_basis += h2_ansatz(0.2);
+    x_basis += Hadamard::new(0);
+    x_basis += Hadamard::new(1);
+    x_basis += PragmaRepeatedMeasurement::new("ro_x".to_string(), 1000, None);
+
+    QuantumProgram::PauliZProduct {
+        measurement: PauliZProduct {
+            input: h2_hamiltonian(),
+            circuits: vec![z_basis, x_basis],
+            constant_circuit: None,
+        },
+        input_parameter_names: vec![],
+    }
+}
+
+/// Example comparing the JSON and bincode serialization of a quantum program.
+pub fn serialization_formats_main() {
+    println!(">> Serialization formats example start.");
+    let program = h2_program();
+    let json = serialize_program_json(&program);
+    let bytes = serialize_program_bincode(&program);
+    println!("JSON size: {} bytes", json.len());
+    println!("Bincode size: {} bytes", bytes.len());
+    println!(
+        "Bincode needs {:.0}% of the JSON size",
+        100.0 * bytes.len() as f64 / json.len() as f64
+    );
+
+    // Validation check: both formats recover the original program
+    let program_from_json: QuantumProgram = serde_json::from_str(&json).unwrap();
+    assert_eq!(program_from_json, program);
+    let (program_from_bincode, _): (QuantumProgram, usize) =
+        bincode::serde::decode_from_slice(&bytes, bincode::config::legacy()).unwrap();
+    assert_eq!(program_from_bincode, program);
+    println!(">> Serialization formats example end.");
+}
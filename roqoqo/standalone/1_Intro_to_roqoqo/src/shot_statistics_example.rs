@@ -0,0 +1,87 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::expectation_values;
+use crate::measurement_builders::single_pauli_z;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+
+// Expectation values estimated from N projective measurements fluctuate from run to run. Every shot gives
+// +1 or -1, so for the state RotateY(theta)|0> a single shot of Z has the variance 1 - cos^2(theta) = sin^2(theta).
+// The mean of N independent shots has the standard deviation sin(theta) / sqrt(N): four times as many shots
+// halve the statistical error. Here the estimate is repeated many times for each N and the empirical
+// standard deviation is compared with this prediction.
+
+/// Rotation angle of the measured state RotateY(theta)|0> with < Z > = cos(theta).
+const THETA: f64 = 1.0;
+
+/// Number of repeated estimates for each number of shots.
+const NUMBER_TRIALS: usize = 200;
+
+/// Estimates < Z > from `shots` measurements `trials` times and returns the mean and the standard deviation.
+///
+/// The sample standard deviation needs at least two trials, fewer trials panic.
+///
+pub fn estimate_with_error(shots: usize, trials: usize) -> (f64, f64) {
+    assert!(
+        trials >= 2,
+        "The standard deviation needs at least two trials, got {}",
+        trials
+    );
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 1, true);
+    circuit += RotateY::new(0, CalculatorFloat::from(THETA));
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), shots, None);
+    let input = single_pauli_z(0, "Z0");
+
+    let backend = Backend::new(1);
+    let estimates: Vec<f64> = (0..trials)
+        .map(|_| {
+            let (result_bit_registers, _result_float_registers, _result_complex_registers) =
+                backend.run_circuit(&circuit).unwrap();
+            expectation_values(&input, &result_bit_registers)["Z0"]
+        })
+        .collect();
+    let mean = estimates.iter().sum::<f64>() / trials as f64;
+    let variance = estimates
+        .iter()
+        .map(|estimate| (estimate - mean).powi(2))
+        .sum::<f64>()
+        / (trials - 1) as f64;
+    (mean, variance.sqrt())
+}
+
+/// Example comparing the measured statistical error of < Z > with the 1/sqrt(N) prediction.
+pub fn shot_statistics_main() {
+    println!(">> Shot statistics example start.");
+    println!("Exact value < Z > = {:.4}", THETA.cos());
+    println!("   Shots |   Mean | Std. dev. | Predicted");
+    let mut deviations = Vec::new();
+    for shots in [25, 100, 400, 1600] {
+        let (mean, deviation) = estimate_with_error(shots, NUMBER_TRIALS);
+        let predicted = THETA.sin() / (shots as f64).sqrt();
+        println!(
+            "{:>8} | {:.4} | {:>9.4} | {:>9.4}",
+            shots, mean, deviation, predicted
+        );
+        deviations.push(deviation);
+    }
+
+    // Validation check: quadrupling the number of shots roughly halves the standard deviation
+    for pair in deviations.windows(2) {
+        let ratio = pair[0] / pair[1];
+        assert!(ratio > 1.5 && ratio < 2.6);
+    }
+    println!(">> Shot statistics example end.");
+}
@@ -0,0 +1,438 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::parallel_eval::{eval_parameter_grid, eval_parameter_grid_sequential};
+use crate::parameter_shift::{expectation_value, gradient};
+use nalgebra::DMatrix;
+use ndarray::Array1;
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::measurements::PauliZProductInput;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::time::Instant;
+
+// A very simple Variational Hamiltonian Ansatz (VHA), following the qoqo example "Simple VHA with qoqo".
+// The goal is to approximate the ground state of a spin chain with three sites, periodic boundary conditions
+// and the Hamiltonian H = B (Z0 + Z1 + Z2) + t (X0 X1 + X1 X2 + X0 X2).
+// The trial states are obtained by a pseudo time evolution exp(-i theta_(k, alpha) H_alpha) under the single
+// terms H_alpha of the Hamiltonian, where the pseudo times theta_(k, alpha) of each layer k are optimized.
+// For detailed discussions of variational algorithms see http://arxiv.org/abs/1304.3061 and
+// http://arxiv.org/abs/1509.04279.
+
+/// Number of qubits (spins) in the chain.
+pub const NUMBER_QUBITS: usize = 3;
+/// Magnetic field B.
+pub const MAGNETIC_FIELD: f64 = 1.0;
+/// Hopping parameter t.
+pub const HOPPING_PARAMETER: f64 = 3.0;
+/// Number of layers of the pseudo time evolution.
+pub const NUMBER_LAYERS: usize = 2;
+/// Number of variational parameters: one per Hamiltonian term and layer.
+pub const NUMBER_PARAMETERS: usize = 2 * NUMBER_QUBITS * NUMBER_LAYERS;
+/// Number of projective measurements per measurement circuit.
+const NUMBER_MEASUREMENTS: usize = 2000;
+
+/// Pairs of qubits coupled by the hopping terms, including the periodic boundary.
+const HOPPING_PAIRS: [(usize, usize); 3] = [(0, 1), (1, 2), (0, 2)];
+
+/// Circuit initializing the state.
+///
+/// We "cheat" and directly set the state vector on the simulator with a `PragmaSetStateVector`.
+/// The initial state is 50% in the single excitation subspace and 50% fully occupied:
+/// 1/sqrt(6) (|001> + |010> + |100>) + 1/sqrt(2) |111>.
+///
+fn initialization_circuit() -> Circuit {
+    let single = 1.0 / 6_f64.sqrt();
+    let full = 1.0 / 2_f64.sqrt();
+    let statevector: Array1<Complex64> = Array1::from(
+        [0.0, single, single, 0.0, single, 0.0, 0.0, full]
+            .iter()
+            .map(|value| Complex64::new(*value, 0.0))
+            .collect::<Vec<Complex64>>(),
+    );
+    let mut circuit = Circuit::new();
+    circuit += PragmaSetStateVector::new(statevector);
+    circuit
+}
+
+/// Appends the evolution exp(-i angle/2 X_k X_l) to `circuit`.
+///
+/// The XX interaction is decomposed into Hadamard gates rotating into the X-basis and a ZZ rotation
+/// built from a `RotateZ` between two CNOT gates.
+///
+fn add_xx_rotation(circuit: &mut Circuit, k: usize, l: usize, angle: f64) {
+    circuit.add_operation(Hadamard::new(k));
+    circuit.add_operation(Hadamard::new(l));
+    circuit.add_operation(CNOT::new(l, k));
+    circuit.add_operation(RotateZ::new(k, CalculatorFloat::from(angle)));
+    circuit.add_operation(CNOT::new(l, k));
+    circuit.add_operation(Hadamard::new(k));
+    circuit.add_operation(Hadamard::new(l));
+}
+
+/// Builds the VHA trial state for the variational parameters `params`.
+///
+/// Each layer applies the hopping terms followed by the magnetic field terms. Every parameter is the angle
+/// of exactly one rotation, so the gradient can be measured with the parameter-shift rule.
+///
+pub fn vha_ansatz(params: &[f64]) -> Circuit {
+    assert_eq!(params.len(), NUMBER_PARAMETERS);
+    let mut circuit = initialization_circuit();
+    for layer in params.chunks(2 * NUMBER_QUBITS) {
+        let (hopping, magnetic) = layer.split_at(NUMBER_QUBITS);
+        for ((k, l), angle) in HOPPING_PAIRS.iter().zip(hopping.iter()) {
+            add_xx_rotation(&mut circuit, *k, *l, *angle);
+        }
+        for (qubit, angle) in magnetic.iter().enumerate() {
+            circuit += RotateZ::new(qubit, CalculatorFloat::from(*angle));
+        }
+    }
+    circuit
+}
+
+/// Measurement circuit for the magnetic field terms, measured in the Z-basis into the readout `ro_z`.
+pub fn z_basis_circuit(params: &[f64]) -> Circuit {
+    z_basis_measurement(vha_ansatz(params))
+}
+
+/// Measures the state prepared by `state_preparation` in the Z-basis into the readout `ro_z`.
+pub fn z_basis_measurement(state_preparation: Circuit) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro_z".to_string(), NUMBER_QUBITS, true);
+    circuit += state_preparation;
+    circuit += PragmaRepeatedMeasurement::new("ro_z".to_string(), NUMBER_MEASUREMENTS, None);
+    circuit
+}
+
+/// Measurement circuit for the hopping terms, rotated to the X-basis and measured into the readout `ro_x`.
+pub fn x_basis_circuit(params: &[f64]) -> Circuit {
+    x_basis_measurement(vha_ansatz(params))
+}
+
+/// Rotates the state prepared by `state_preparation` to the X-basis and measures it into the readout `ro_x`.
+pub fn x_basis_measurement(state_preparation: Circuit) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro_x".to_string(), NUMBER_QUBITS, true);
+    circuit += state_preparation;
+    for qubit in 0..NUMBER_QUBITS {
+        circuit += Hadamard::new(qubit);
+    }
+    circuit += PragmaRepeatedMeasurement::new("ro_x".to_string(), NUMBER_MEASUREMENTS, None);
+    circuit
+}
+
+/// Measurement input of the magnetic energy B (Z0 + Z1 + Z2) from the readout `ro_z`.
+pub fn z_basis_input() -> PauliZProductInput {
+    let mut measurement_input = PauliZProductInput::new(NUMBER_QUBITS, false);
+    let mut linear: HashMap<usize, f64> = HashMap::new();
+    for qubit in 0..NUMBER_QUBITS {
+        let index = measurement_input
+            .add_pauliz_product("ro_z".to_string(), vec![qubit])
+            .unwrap();
+        linear.insert(index, MAGNETIC_FIELD);
+    }
+    measurement_input
+        .add_linear_exp_val("energy".to_string(), linear)
+        .unwrap();
+    measurement_input
+}
+
+/// Measurement input of the hopping energy t (X0 X1 + X1 X2 + X0 X2) from the readout `ro_x`.
+pub fn x_basis_input() -> PauliZProductInput {
+    let mut measurement_input = PauliZProductInput::new(NUMBER_QUBITS, false);
+    let mut linear: HashMap<usize, f64> = HashMap::new();
+    for (k, l) in HOPPING_PAIRS.iter() {
+        let index = measurement_input
+            .add_pauliz_product("ro_x".to_string(), vec![*k, *l])
+            .unwrap();
+        linear.insert(index, HOPPING_PARAMETER);
+    }
+    measurement_input
+        .add_linear_exp_val("energy".to_string(), linear)
+        .unwrap();
+    measurement_input
+}
+
+/// Measures the energy expectation value of the trial state for the parameters `params`.
+pub fn vha_energy(params: &[f64]) -> f64 {
+    expectation_value(z_basis_circuit(params), &z_basis_input())
+        + expectation_value(x_basis_circuit(params), &x_basis_input())
+}
+
+/// Measures the gradient of the energy with the parameter-shift rule.
+///
+/// The gradient of the sum of the magnetic and hopping energies is the sum of their gradients.
+///
+pub fn vha_gradient(params: &[f64]) -> Vec<f64> {
+    let z_gradient = gradient(z_basis_circuit, &z_basis_input(), params);
+    let x_gradient = gradient(x_basis_circuit, &x_basis_input(), params);
+    z_gradient
+        .iter()
+        .zip(x_gradient.iter())
+        .map(|(z, x)| z + x)
+        .collect()
+}
+
+/// Builds the Hamiltonian matrix of the spin chain in the computational basis.
+pub fn hamiltonian_matrix() -> DMatrix<f64> {
+    let dimension = 2_usize.pow(NUMBER_QUBITS as u32);
+    let mut hamiltonian = DMatrix::<f64>::zeros(dimension, dimension);
+    for state in 0..dimension {
+        for qubit in 0..NUMBER_QUBITS {
+            // Z_i is +1 for qubit i in |0> and -1 for qubit i in |1>
+            let sign = if state & (1 << qubit) == 0 { 1.0 } else { -1.0 };
+            hamiltonian[(state, state)] += MAGNETIC_FIELD * sign;
+        }
+        for (k, l) in HOPPING_PAIRS.iter() {
+            // X_k X_l flips both qubits
+            let flipped = state ^ (1 << k) ^ (1 << l);
+            hamiltonian[(flipped, state)] += HOPPING_PARAMETER;
+        }
+    }
+    hamiltonian
+}
+
+/// Calculates the exact ground state energy of the spin chain by diagonalizing the Hamiltonian.
+pub fn exact_ground_state_energy() -> f64 {
+    hamiltonian_matrix().symmetric_eigen().eigenvalues.min()
+}
+
+/// Calculates the exact energy Tr(rho H) of the state prepared by `state_preparation`, without shot noise.
+///
+/// The density matrix is read out with a `PragmaGetDensityMatrix`, so the preparation may contain noise pragmas.
+///
+pub fn exact_state_energy(state_preparation: &Circuit) -> f64 {
+    let dimension = 2_usize.pow(NUMBER_QUBITS as u32);
+    let mut circuit = Circuit::new();
+    circuit += DefinitionComplex::new("rho".to_string(), dimension * dimension, true);
+    circuit += state_preparation.clone();
+    circuit += PragmaGetDensityMatrix::new("rho".to_string(), None);
+
+    let backend = Backend::new(NUMBER_QUBITS);
+    let (_result_bit_registers, _result_float_registers, result_complex_registers) =
+        backend.run_circuit(&circuit).unwrap();
+    let rho = &result_complex_registers["rho"][0];
+    let hamiltonian = hamiltonian_matrix();
+    let mut energy = 0.0;
+    for row in 0..dimension {
+        for column in 0..dimension {
+            energy += (rho[row * dimension + column] * hamiltonian[(column, row)]).re;
+        }
+    }
+    energy
+}
+
+/// Minimizes `cost` with a simple derivative-free compass search starting from `initial`.
+///
+/// Each parameter is moved up and down by the current step size and the change is kept if the cost decreases.
+/// When no move improves the cost the step size is halved. Returns the parameters and the final cost.
+/// For a cost estimated from measurements the final cost is the smallest of many noisy estimates and biased low,
+/// it has to be re-evaluated at the returned parameters.
+///
+pub fn compass_search(cost: impl Fn(&[f64]) -> f64, initial: Vec<f64>) -> (Vec<f64>, f64) {
+    compass_search_with_callback(cost, initial, |_, _, _| {})
+}
+
+/// Compass search as in [compass_search], reporting the progress to `callback`.
+///
+/// The callback receives the iteration index, the current parameters and the current cost, first with index 0
+/// for the initial parameters and then after every sweep over all parameters.
+///
+pub fn compass_search_with_callback(
+    cost: impl Fn(&[f64]) -> f64,
+    initial: Vec<f64>,
+    mut callback: impl FnMut(usize, &[f64], f64),
+) -> (Vec<f64>, f64) {
+    let mut parameters = initial;
+    let mut best = cost(&parameters);
+    let mut step = 0.5;
+    let mut iteration = 0;
+    callback(iteration, &parameters, best);
+    while step > 0.05 {
+        let mut improved = false;
+        for index in 0..parameters.len() {
+            for direction in [1.0, -1.0] {
+                let mut trial = parameters.clone();
+                trial[index] += direction * step;
+                let value = cost(&trial);
+                if value < best {
+                    best = value;
+                    parameters = trial;
+                    improved = true;
+                }
+            }
+        }
+        if !improved {
+            step /= 2.0;
+        }
+        iteration += 1;
+        callback(iteration, &parameters, best);
+    }
+    (parameters, best)
+}
+
+/// Optimizes the VHA parameters with a compass search starting from zero, reporting every iteration to `callback`.
+///
+/// The callback receives the iteration index, the current parameters and the best measured energy.
+/// Returns the optimized parameters and their exact energy, evaluated without shot noise after the search.
+///
+pub fn run_vha_with_callback(callback: impl FnMut(usize, &[f64], f64)) -> (Vec<f64>, f64) {
+    let (parameters, _measured_energy) =
+        compass_search_with_callback(vha_energy, vec![0.0; NUMBER_PARAMETERS], callback);
+    let energy = exact_state_energy(&vha_ansatz(&parameters));
+    (parameters, energy)
+}
+
+/// Runs the VHA with a derivative-free optimization of the variational parameters.
+///
+/// The best measured energy is printed in every iteration of the optimization. The final energy is evaluated
+/// exactly at the optimized parameters, since the minimum of noisy estimates lies below their true energy.
+///
+pub fn run_simple_vha() {
+    println!(">> Simple VHA example start.");
+    let initial_energy = exact_state_energy(&vha_ansatz(&[0.0; NUMBER_PARAMETERS]));
+    let mut iterations = Vec::new();
+    let (parameters, energy) = run_vha_with_callback(|iteration, _parameters, cost| {
+        iterations.push(iteration);
+        println!("Iteration {}: best measured energy {:.4}", iteration, cost);
+    });
+    let exact_energy = exact_ground_state_energy();
+
+    println!("Optimized parameters theta: {:?}", parameters);
+    println!("Energy of the initial state: {:.4}", initial_energy);
+    println!("Calculated approximate energy value: {:.4}", energy);
+    println!("Energy of the exact ground state: {:.4}", exact_energy);
+    println!(
+        "Difference between VHA result and exact result: {:.4}",
+        energy - exact_energy
+    );
+
+    // Validation check: the optimization lowers the energy, the exact energy of a trial state is variationally
+    // bounded by the ground state energy
+    assert!(energy < initial_energy);
+    assert!(energy >= exact_energy - 1e-10);
+    assert!(energy < exact_energy + 0.4);
+
+    // Validation check: the callback ran for the initial parameters and after every sweep
+    assert!(iterations
+        .iter()
+        .enumerate()
+        .all(|(index, iteration)| index == *iteration));
+
+    // Validation check: for a cost that never improves every sweep halves the step size, from 0.5 down to
+    // 0.03125 in four sweeps, each sweep evaluates the cost twice
+    let evaluations = Cell::new(0);
+    let mut calls = 0;
+    let (minimum, cost) = compass_search_with_callback(
+        |params| {
+            evaluations.set(evaluations.get() + 1);
+            params[0].abs()
+        },
+        vec![0.0],
+        |_, _, _| calls += 1,
+    );
+    assert_eq!(calls, 5);
+    assert_eq!(evaluations.get(), 1 + 4 * 2);
+    assert_eq!((minimum, cost), (vec![0.0], 0.0));
+    println!(">> Simple VHA example end.");
+}
+
+/// Runs the VHA with gradient descent, measuring the gradient with the parameter-shift rule.
+///
+/// In each of the `iterations` steps all parameters are moved against the gradient scaled by `learning_rate`.
+///
+pub fn run_vha_gradient_descent(learning_rate: f64, iterations: usize) {
+    println!(">> VHA gradient descent example start.");
+    // All angles zero is a stationary point of the energy, so we start slightly away from it
+    let mut parameters = vec![0.1; NUMBER_PARAMETERS];
+    let initial_energy = vha_energy(&parameters);
+    println!("Iteration 0: energy {:.4}", initial_energy);
+    let mut energy = initial_energy;
+    for iteration in 1..=iterations {
+        let gradient = vha_gradient(&parameters);
+        for (parameter, derivative) in parameters.iter_mut().zip(gradient.iter()) {
+            *parameter -= learning_rate * derivative;
+        }
+        energy = vha_energy(&parameters);
+        println!("Iteration {}: energy {:.4}", iteration, energy);
+    }
+    println!(
+        "Energy of the exact ground state: {:.4}",
+        exact_ground_state_energy()
+    );
+
+    // Validation check: gradient descent lowers the energy
+    assert!(energy < initial_energy);
+    println!(">> VHA gradient descent example end.");
+}
+
+/// Scans the energy along a line in parameter space, evaluating the grid points in parallel.
+///
+/// All variational parameters are set to the same angle theta. The scan is repeated sequentially
+/// to compare the timing and the results.
+///
+pub fn run_vha_parameter_scan() {
+    println!(">> VHA parameter scan example start.");
+    let number_points: usize = 200;
+    let grid: Vec<f64> = (0..number_points)
+        .map(|step| -PI + 2.0 * PI * step as f64 / number_points as f64)
+        .collect();
+    let z_builder = |theta: f64| z_basis_circuit(&[theta; NUMBER_PARAMETERS]);
+    let x_builder = |theta: f64| x_basis_circuit(&[theta; NUMBER_PARAMETERS]);
+
+    let start = Instant::now();
+    let parallel: Vec<f64> = eval_parameter_grid(z_builder, &z_basis_input(), &grid)
+        .iter()
+        .zip(eval_parameter_grid(x_builder, &x_basis_input(), &grid).iter())
+        .map(|(z, x)| z + x)
+        .collect();
+    let parallel_time = start.elapsed();
+
+    let start = Instant::now();
+    let sequential: Vec<f64> = eval_parameter_grid_sequential(z_builder, &z_basis_input(), &grid)
+        .iter()
+        .zip(eval_parameter_grid_sequential(x_builder, &x_basis_input(), &grid).iter())
+        .map(|(z, x)| z + x)
+        .collect();
+    let sequential_time = start.elapsed();
+
+    let (best_index, best_energy) = parallel
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .unwrap();
+    println!(
+        "Lowest energy {:.4} at theta = {:.4}",
+        best_energy, grid[best_index]
+    );
+    println!(
+        "Parallel scan: {:?}, sequential scan: {:?} on {} threads",
+        parallel_time,
+        sequential_time,
+        rayon::current_num_threads()
+    );
+
+    // Validation check: both scans agree at every grid point up to shot noise.
+    // The standard deviation of the energy estimate is at most (3 B + 3 t) / sqrt(NUMBER_MEASUREMENTS),
+    // a mix-up of grid points would give differences of the order of the energy range instead.
+    let tolerance = 5.0 * 2_f64.sqrt() * 3.0 * (MAGNETIC_FIELD + HOPPING_PARAMETER)
+        / (NUMBER_MEASUREMENTS as f64).sqrt();
+    for (parallel_energy, sequential_energy) in parallel.iter().zip(sequential.iter()) {
+        assert!((parallel_energy - sequential_energy).abs() < tolerance);
+    }
+    println!(">> VHA parameter scan example end.");
+}
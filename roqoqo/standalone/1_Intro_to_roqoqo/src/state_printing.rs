@@ -0,0 +1,68 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+// Helpers to print state vectors read out with `PragmaGetStateVector` in a human-readable form.
+// Basis states are labelled in the usual ket notation with qubit 0 as the rightmost bit,
+// so the amplitude at index k belongs to the binary representation of k.
+
+/// Amplitudes with a magnitude below this threshold are not printed by `print_statevector`.
+pub const DEFAULT_THRESHOLD: f64 = 1e-10;
+
+/// Returns the ket label of the basis state `index`, *e.g.* |101⟩ for the index 5 on three qubits.
+pub fn basis_label(index: usize, num_qubits: usize) -> String {
+    format!("|{:0width$b}⟩", index, width = num_qubits)
+}
+
+/// Formats every amplitude with a magnitude of at least `threshold` as `|bitstring⟩: magnitude ∠ phase`.
+///
+/// The phase is given in units of pi.
+///
+pub fn format_statevector(
+    amplitudes: &[Complex64],
+    num_qubits: usize,
+    threshold: f64,
+) -> Vec<String> {
+    amplitudes
+        .iter()
+        .enumerate()
+        .filter(|(_, amplitude)| amplitude.norm() >= threshold)
+        .map(|(index, amplitude)| {
+            format!(
+                "{}: {:.4} ∠ {:.4}π",
+                basis_label(index, num_qubits),
+                amplitude.norm(),
+                amplitude.arg() / PI
+            )
+        })
+        .collect()
+}
+
+/// Prints all non-vanishing amplitudes of a state vector.
+pub fn print_statevector(amplitudes: &[Complex64], num_qubits: usize) {
+    for line in format_statevector(amplitudes, num_qubits, DEFAULT_THRESHOLD) {
+        println!("{}", line);
+    }
+}
+
+/// Prints the probability of every basis state of a state vector.
+pub fn print_probabilities(amplitudes: &[Complex64], num_qubits: usize) {
+    for (index, amplitude) in amplitudes.iter().enumerate() {
+        println!(
+            "{}: {:.4}",
+            basis_label(index, num_qubits),
+            amplitude.norm_sqr()
+        );
+    }
+}
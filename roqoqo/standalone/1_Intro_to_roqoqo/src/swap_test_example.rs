@@ -0,0 +1,107 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::toffoli_example::toffoli_circuit;
+use crate::verification::circuit_matrix;
+use num_complex::Complex64;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::collections::HashMap;
+
+// The SWAP test estimates the squared overlap |<psi|phi>|^2 of two states without reading them out.
+// An ancilla in |+> controls a SWAP of the two states, and a final Hadamard on the ancilla interferes the
+// swapped and unswapped branches: the ancilla is measured in |0> with probability (1 + |<psi|phi>|^2) / 2.
+// The controlled SWAP (Fredkin gate) is not available as a native gate. It is a Toffoli gate framed by two
+// CNOTs, using the decomposed Toffoli gate of `toffoli_example`.
+
+/// Number of measurements of the ancilla.
+const NUMBER_MEASUREMENTS: usize = 2000;
+
+/// Controlled SWAP of the qubits `a` and `b`, applied if `control` is in |1>.
+///
+/// The CNOT(b, a) gates around a Toffoli with target b turn the controlled flip into a controlled swap,
+/// like the three CNOTs of an ordinary SWAP where only the middle one needs the extra control.
+///
+pub fn controlled_swap(control: usize, a: usize, b: usize) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += CNOT::new(b, a);
+    circuit += toffoli_circuit(control, a, b);
+    circuit += CNOT::new(b, a);
+    circuit
+}
+
+/// Estimates the squared overlap of the single-qubit states prepared by `prep_a` and `prep_b`.
+///
+/// Both preparation circuits act on qubit 0. They are moved to the qubits 1 and 2, the ancilla is qubit 0.
+///
+pub fn swap_test(prep_a: Circuit, prep_b: Circuit) -> f64 {
+    let mut circuit = Circuit::new();
+    // The repeated measurement reads out all qubits, the ancilla is the first entry
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    circuit += prep_a
+        .remap_qubits(&HashMap::from([(0, 1), (1, 0)]))
+        .unwrap();
+    circuit += prep_b
+        .remap_qubits(&HashMap::from([(0, 2), (2, 0)]))
+        .unwrap();
+    circuit += Hadamard::new(0);
+    circuit += controlled_swap(0, 1, 2);
+    circuit += Hadamard::new(0);
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), NUMBER_MEASUREMENTS, None);
+
+    let backend = Backend::new(3);
+    let (result_bit_registers, _result_float_registers, _result_complex_registers) =
+        backend.run_circuit(&circuit).unwrap();
+    let zeros = result_bit_registers["ro"]
+        .iter()
+        .filter(|measurement| !measurement[0])
+        .count();
+    let probability_zero = zeros as f64 / NUMBER_MEASUREMENTS as f64;
+    2.0 * probability_zero - 1.0
+}
+
+/// Example estimating the overlaps of identical, orthogonal and partially overlapping states.
+pub fn swap_test_main() {
+    println!(">> SWAP test example start.");
+    let zero = Circuit::new();
+    let mut one = Circuit::new();
+    one += PauliX::new(0);
+    let mut plus = Circuit::new();
+    plus += Hadamard::new(0);
+
+    let identical = swap_test(zero.clone(), zero.clone());
+    let orthogonal = swap_test(zero.clone(), one);
+    let partial = swap_test(zero, plus);
+    println!("Overlap of |0> with |0>: {:.4}", identical);
+    println!("Overlap of |0> with |1>: {:.4}", orthogonal);
+    println!("Overlap of |0> with |+>: {:.4} (exact: 0.5)", partial);
+
+    // Validation check: identical states always give 1, orthogonal states 0 up to shot noise
+    assert!((identical - 1.0).abs() < 1e-10);
+    assert!(orthogonal.abs() < 0.1);
+    assert!((partial - 0.5).abs() < 0.1);
+
+    // Validation check: the decomposed controlled SWAP exchanges qubits 1 and 2 exactly when qubit 0 is in |1>
+    let matrix = circuit_matrix(&controlled_swap(0, 1, 2), 3).unwrap();
+    for input in 0..8_usize {
+        let bit_1 = (input >> 1) & 1;
+        let bit_2 = (input >> 2) & 1;
+        let output = if input & 1 == 1 {
+            (input & 1) | (bit_1 << 2) | (bit_2 << 1)
+        } else {
+            input
+        };
+        assert!((matrix[(output, input)] - Complex64::new(1.0, 0.0)).norm() < 1e-10);
+    }
+    println!(">> SWAP test example end.");
+}
@@ -0,0 +1,82 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use qoqo_calculator::{Calculator, CalculatorFloat};
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+// Rotation angles in roqoqo are `CalculatorFloat` values. Besides plain floats they can hold symbolic
+// expressions like "theta" or "theta / 2". A circuit with symbolic parameters works as a template:
+// the same circuit can be reused with different parameter values, which is the basis for variational
+// algorithms. Before a circuit can be simulated all symbolic parameters have to be replaced by numbers.
+
+/// Builds a circuit template with the symbolic parameters `theta` and `phi`.
+pub fn symbolic_circuit() -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += RotateX::new(0, "theta".into());
+    circuit += RotateY::new(1, "phi".into());
+    circuit += CNOT::new(0, 1);
+    // Parameters can also enter as expressions
+    circuit += RotateZ::new(1, CalculatorFloat::from("theta / 2"));
+    circuit += RotateZ::new(0, CalculatorFloat::from("phi") * 2.0);
+    circuit
+}
+
+/// Replaces all symbolic parameters in `circuit` with the values in `parameters`.
+///
+/// The values are collected in a `Calculator` that evaluates the symbolic expressions of every operation.
+///
+pub fn substitute(circuit: &Circuit, parameters: &HashMap<String, f64>) -> Circuit {
+    let mut calculator = Calculator::new();
+    for (name, value) in parameters.iter() {
+        calculator.set_variable(name, *value);
+    }
+    circuit.substitute_parameters(&calculator).unwrap()
+}
+
+/// Example substituting symbolic parameters before running a circuit.
+pub fn symbolic_parameters_main() {
+    println!(">> Symbolic parameters example start.");
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 2, true);
+    circuit += symbolic_circuit();
+    circuit += PragmaRepeatedMeasurement::new("ro".to_string(), 100, None);
+    println!("Symbolic circuit: {}", circuit);
+
+    // The simulator can not run a circuit with symbolic parameters
+    let backend = Backend::new(2);
+    match backend.run_circuit(&circuit) {
+        Ok(_) => println!("Unexpectedly ran the symbolic circuit."),
+        Err(error) => println!("Running the symbolic circuit fails: {:?}", error),
+    }
+
+    // Substitute all parameters at once
+    let parameters: HashMap<String, f64> =
+        HashMap::from([("theta".to_string(), PI), ("phi".to_string(), PI / 2.0)]);
+    let concrete_circuit = substitute(&circuit, &parameters);
+    println!("Circuit after substitution: {}", concrete_circuit);
+
+    let (result_bit_registers, _result_float_registers, _result_complex_registers) =
+        backend.run_circuit(&concrete_circuit).unwrap();
+    println!("First measurements: {:?}", &result_bit_registers["ro"][..5]);
+
+    // Validation check: no symbolic parameters are left and theta = pi flips qubit 0
+    assert!(circuit.is_parametrized());
+    assert!(!concrete_circuit.is_parametrized());
+    assert!(result_bit_registers["ro"]
+        .iter()
+        .all(|measurement| measurement[0]));
+    println!(">> Symbolic parameters example end.");
+}
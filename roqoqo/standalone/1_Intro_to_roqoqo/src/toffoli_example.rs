@@ -0,0 +1,106 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::verification::circuit_matrix;
+use num_complex::Complex64;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+
+// The Toffoli gate (controlled-controlled NOT) flips the target qubit if both control qubits are in |1>,
+// on computational basis states it maps the target t onto t XOR (c1 AND c2). It is not a native gate and is
+// decomposed into CNOT, Hadamard and T gates. The Hadamard gates on the target turn the Toffoli gate into a
+// controlled-controlled Z with the phase (-1)^(c1 c2 t). Using 4 c1 c2 t = c1 + c2 + t - (c1 XOR c2) - (c1 XOR t)
+// - (c2 XOR t) + (c1 XOR c2 XOR t), this phase is a product of T = diag(1, exp(i pi/4)) and T^dagger gates
+// acting on parities of the qubits, which the CNOT gates compute.
+
+/// Toffoli gate flipping `target` if both `control1` and `control2` are in |1>.
+///
+/// Standard decomposition with six CNOTs, two Hadamard gates and seven T or T^dagger gates.
+///
+pub fn toffoli_circuit(control1: usize, control2: usize, target: usize) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += Hadamard::new(target);
+    circuit += CNOT::new(control2, target);
+    circuit += InvTGate::new(target);
+    circuit += CNOT::new(control1, target);
+    circuit += TGate::new(target);
+    circuit += CNOT::new(control2, target);
+    circuit += InvTGate::new(target);
+    circuit += CNOT::new(control1, target);
+    circuit += TGate::new(control2);
+    circuit += TGate::new(target);
+    circuit += Hadamard::new(target);
+    circuit += CNOT::new(control1, control2);
+    circuit += TGate::new(control1);
+    circuit += InvTGate::new(control2);
+    circuit += CNOT::new(control1, control2);
+    circuit
+}
+
+/// Runs the Toffoli circuit on the basis state with index `input` and returns the measured bits of qubits 0, 1, 2.
+fn run_basis_state(input: usize) -> Vec<bool> {
+    let mut circuit = Circuit::new();
+    circuit += DefinitionBit::new("ro".to_string(), 3, true);
+    for qubit in 0..3 {
+        if input & (1 << qubit) != 0 {
+            circuit += PauliX::new(qubit);
+        }
+    }
+    circuit += toffoli_circuit(0, 1, 2);
+    for qubit in 0..3 {
+        circuit += MeasureQubit::new(qubit, "ro".to_string(), qubit);
+    }
+
+    let backend = Backend::new(3);
+    let (result_bit_registers, _result_float_registers, _result_complex_registers) =
+        backend.run_circuit(&circuit).unwrap();
+    result_bit_registers["ro"][0].clone()
+}
+
+/// Example checking the decomposed Toffoli gate on all computational basis states.
+pub fn toffoli_main() {
+    println!(">> Toffoli gate example start.");
+    println!("Decomposed Toffoli circuit: {}", toffoli_circuit(0, 1, 2));
+    println!("c1 c2 t | c1 c2 t");
+    for input in 0..8 {
+        let (control1, control2, target) = (input & 1 != 0, input & 2 != 0, input & 4 != 0);
+        let output = run_basis_state(input);
+        println!(
+            " {}  {} {} |  {}  {} {}",
+            control1 as u8,
+            control2 as u8,
+            target as u8,
+            output[0] as u8,
+            output[1] as u8,
+            output[2] as u8
+        );
+
+        // Validation check: the controls are unchanged, the target flips exactly if both controls are 1
+        assert_eq!(
+            output,
+            vec![control1, control2, target ^ (control1 && control2)]
+        );
+    }
+
+    // Validation check: the decomposition is exact including phases, its matrix is the permutation
+    // exchanging |011> and |111>
+    let matrix = circuit_matrix(&toffoli_circuit(0, 1, 2), 3).unwrap();
+    for column in 0..8 {
+        let row = if column & 3 == 3 { column ^ 4 } else { column };
+        for index in 0..8 {
+            let expected = if index == row { 1.0 } else { 0.0 };
+            assert!((matrix[(index, column)] - Complex64::new(expected, 0.0)).norm() < 1e-10);
+        }
+    }
+    println!(">> Toffoli gate example end.");
+}
@@ -0,0 +1,121 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use nalgebra::{DMatrix, DVector};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+
+// Digital quantum simulation of the time evolution exp(-i H t) |00> for the two-qubit Hamiltonian
+// H = X_0 X_1 + Z_0, where Z acts on qubit 0.
+// The two terms do not commute, so the evolution is approximated with first-order Trotterization:
+// exp(-i H t) ≈ (exp(-i X_0 X_1 dt) exp(-i Z_0 dt))^steps with the time step dt = t / steps.
+// The error of one step is of order dt^2, so the total error decreases as 1 / steps.
+// In each step exp(-i X_0 X_1 dt) is built from Hadamard gates mapping X to Z, a CNOT-RotateZ-CNOT
+// block for exp(-i Z_0 Z_1 dt) and Hadamard gates mapping back.
+// Since RotateZ(theta) = exp(-i theta Z / 2), both terms use the rotation angle 2 dt.
+
+/// Evolution time.
+const EVOLUTION_TIME: f64 = 1.0;
+
+/// Builds the first-order Trotter circuit for exp(-i H t) with `steps` time steps of length t / steps.
+pub fn trotter_circuit(t: f64, steps: usize) -> Circuit {
+    let angle = CalculatorFloat::from(2.0 * t / steps as f64);
+    let mut circuit = Circuit::new();
+    for _ in 0..steps {
+        // exp(-i X_0 X_1 dt)
+        circuit += Hadamard::new(0);
+        circuit += Hadamard::new(1);
+        circuit += CNOT::new(0, 1);
+        circuit += RotateZ::new(1, angle.clone());
+        circuit += CNOT::new(0, 1);
+        circuit += Hadamard::new(0);
+        circuit += Hadamard::new(1);
+        // exp(-i Z_0 dt)
+        circuit += RotateZ::new(0, angle.clone());
+    }
+    circuit
+}
+
+/// Returns the state vector after running `circuit` on |00>.
+fn trotter_state(circuit: &Circuit) -> Vec<Complex64> {
+    let mut readout = Circuit::new();
+    readout += DefinitionComplex::new("psi".to_string(), 4, true);
+    readout += circuit.clone();
+    readout += PragmaGetStateVector::new("psi".to_string(), None);
+
+    let backend = Backend::new(2);
+    let (_result_bit_registers, _result_float_registers, result_complex_registers) =
+        backend.run_circuit(&readout).unwrap();
+    result_complex_registers["psi"][0].clone()
+}
+
+/// Computes exp(-i H t) |00> exactly from the eigendecomposition of H.
+///
+/// The basis index has the state of qubit q in bit q, as in the state vector of the simulator.
+///
+fn exact_state(t: f64) -> Vec<Complex64> {
+    let mut hamiltonian = DMatrix::<f64>::zeros(4, 4);
+    for index in 0..4_usize {
+        // X_0 X_1 flips both bits, Z_0 is diagonal with the sign given by bit 0
+        hamiltonian[(index ^ 0b11, index)] += 1.0;
+        hamiltonian[(index, index)] += if index & 1 == 0 { 1.0 } else { -1.0 };
+    }
+    let eigen = hamiltonian.symmetric_eigen();
+    let eigenvectors = eigen.eigenvectors.map(|x| Complex64::new(x, 0.0));
+    let phases = DVector::from_iterator(
+        4,
+        eigen
+            .eigenvalues
+            .iter()
+            .map(|energy| Complex64::new(0.0, -energy * t).exp()),
+    );
+    let evolution = &eigenvectors * DMatrix::from_diagonal(&phases) * eigenvectors.adjoint();
+    evolution.column(0).iter().cloned().collect()
+}
+
+/// Returns the fidelity |<a|b>|^2 of two normalized state vectors.
+fn fidelity(a: &[Complex64], b: &[Complex64]) -> f64 {
+    let overlap: Complex64 = a.iter().zip(b.iter()).map(|(x, y)| x.conj() * y).sum();
+    overlap.norm_sqr()
+}
+
+/// Example comparing the Trotterized time evolution with the exact evolution for increasing step counts.
+pub fn trotter_main() {
+    println!(">> Trotterization example start.");
+    // Validation check: every step rotates by the angle 2 t / steps
+    let circuit = trotter_circuit(EVOLUTION_TIME, 4);
+    for operation in circuit.iter() {
+        if let Operation::RotateZ(rotation) = operation {
+            assert_eq!(
+                *rotation.theta(),
+                CalculatorFloat::from(2.0 * EVOLUTION_TIME / 4.0)
+            );
+        }
+    }
+
+    let exact = exact_state(EVOLUTION_TIME);
+    let mut previous_fidelity = 0.0;
+    for steps in [1, 2, 4, 8, 16, 32] {
+        let state = trotter_state(&trotter_circuit(EVOLUTION_TIME, steps));
+        let fidelity = fidelity(&exact, &state);
+        println!("Fidelity with {:>2} Trotter steps: {:.8}", steps, fidelity);
+
+        // Validation check: more Trotter steps give a better approximation
+        assert!(fidelity > previous_fidelity);
+        previous_fidelity = fidelity;
+    }
+    assert!(previous_fidelity > 0.999);
+    println!(">> Trotterization example end.");
+}
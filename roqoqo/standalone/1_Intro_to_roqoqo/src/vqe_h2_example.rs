@@ -0,0 +1,106 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::hamiltonian_io::load_pauli_hamiltonian;
+use qoqo_calculator::CalculatorFloat;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::measurements::{PauliZProduct, PauliZProductInput};
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+use std::f64::consts::PI;
+use std::path::Path;
+
+// Variational quantum eigensolver (VQE) for the hydrogen molecule in the minimal STO-3G basis at the
+// bond distance 0.735 Angstrom. After the parity mapping and the removal of the two qubits fixed by
+// symmetries the Hamiltonian acts on two qubits:
+// H = c_I + c_Z0 Z0 + c_Z1 Z1 + c_ZZ Z0 Z1 + c_XX X0 X1.
+// The constant c_I includes the nuclear repulsion, so the ground state energy is the full configuration
+// interaction (FCI) energy of the molecule.
+
+/// Full configuration interaction ground state energy of H2 at 0.735 Angstrom in Hartree.
+pub const FCI_ENERGY: f64 = -1.137306;
+
+/// Number of projective measurements per measurement circuit.
+const NUMBER_MEASUREMENTS: usize = 20000;
+
+/// Measurement input of the two-qubit H2 Hamiltonian, read from the file `h2.json` of this crate.
+///
+/// The Z-terms are measured from the readout `ro_z` of the unrotated circuit, the X0 X1 term from the
+/// readout `ro_x` of a circuit rotated to the X-basis with Hadamard gates.
+/// The constant is the electronic constant -1.052373 plus the nuclear repulsion 0.719969.
+///
+pub fn h2_hamiltonian() -> PauliZProductInput {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("h2.json");
+    load_pauli_hamiltonian(&path).unwrap()
+}
+
+/// Hardware-efficient ansatz with a single variational angle `theta`.
+///
+/// The ansatz prepares cos(theta/2) |q1=1, q0=0> + sin(theta/2) |q1=0, q0=1>, the subspace with one
+/// occupied orbital that contains the ground state of the reduced Hamiltonian.
+///
+pub fn h2_ansatz(theta: f64) -> Circuit {
+    let mut circuit = Circuit::new();
+    circuit += RotateY::new(0, CalculatorFloat::from(theta));
+    circuit += CNOT::new(0, 1);
+    circuit += PauliX::new(1);
+    circuit
+}
+
+/// Measures the energy expectation value of the `hamiltonian` for the ansatz state with the angle `theta`.
+pub fn h2_energy(hamiltonian: &PauliZProductInput, theta: f64) -> f64 {
+    let mut z_basis = Circuit::new();
+    z_basis += DefinitionBit::new("ro_z".to_string(), 2, true);
+    z_basis += h2_ansatz(theta);
+    z_basis += PragmaRepeatedMeasurement::new("ro_z".to_string(), NUMBER_MEASUREMENTS, None);
+
+    let mut x_basis = Circuit::new();
+    x_basis += DefinitionBit::new("ro_x".to_string(), 2, true);
+    x_basis += h2_ansatz(theta);
+    x_basis += Hadamard::new(0);
+    x_basis += Hadamard::new(1);
+    x_basis += PragmaRepeatedMeasurement::new("ro_x".to_string(), NUMBER_MEASUREMENTS, None);
+
+    let measurement = PauliZProduct {
+        input: hamiltonian.clone(),
+        circuits: vec![z_basis, x_basis],
+        constant_circuit: None,
+    };
+    let backend = Backend::new(2);
+    backend.run_measurement(&measurement).unwrap().unwrap()["energy"]
+}
+
+/// Example finding the ground state energy of H2 with a one-dimensional scan of the variational angle.
+pub fn vqe_h2_main() {
+    println!(">> VQE H2 example start.");
+    let hamiltonian = h2_hamiltonian();
+    let number_points: usize = 60;
+    let mut best_theta = 0.0;
+    let mut best_energy = f64::INFINITY;
+    for step in 0..number_points {
+        let theta = -PI + 2.0 * PI * step as f64 / number_points as f64;
+        let energy = h2_energy(&hamiltonian, theta);
+        if energy < best_energy {
+            best_energy = energy;
+            best_theta = theta;
+        }
+    }
+    println!("Optimal angle theta: {:.4}", best_theta);
+    println!(
+        "VQE ground state energy: {:.4} Hartree (FCI: {:.4} Hartree)",
+        best_energy, FCI_ENERGY
+    );
+
+    // Validation check: the scan finds the FCI energy up to the grid and shot noise resolution
+    assert!((best_energy - FCI_ENERGY).abs() < 0.03);
+    println!(">> VQE H2 example end.");
+}
@@ -11,14 +11,22 @@
 // limitations under the License.
 
 use core::f64::consts::PI as Pi;
+use ndarray::Array2;
+use num_complex::Complex64;
 use qoqo_calculator::CalculatorFloat;
 use roqoqo::backends::EvaluatingBackend;
 use roqoqo::{operations as ops, Circuit};
 use roqoqo_quest::Backend;
+use runner::{CircuitRunner, MockRunner, QuestRunner};
+use std::collections::HashMap;
+use std::error::Error;
 
-pub fn main() {
-    println!(">> Teleportation example start.");
+mod runner;
+mod state_printing;
+mod verification;
 
+/// Builds the teleportation protocol sending the state |1> of qubit 0 to qubit 2.
+fn teleportation_circuit() -> Circuit {
     fn prep_psi(angle_thet: CalculatorFloat, angle_phi: CalculatorFloat) -> Circuit {
         let mut circuit = Circuit::new();
         circuit += ops::RotateY::new(0, angle_thet);
@@ -28,6 +36,14 @@ pub fn main() {
 
     let init_circuit = prep_psi(CalculatorFloat::Float(Pi), CalculatorFloat::Float(0f64));
 
+    init_circuit + teleportation_protocol(Circuit::new())
+}
+
+/// Builds the teleportation protocol sending the state of qubit 0 to qubit 2.
+///
+/// The `noise` circuit acts on the entangled resource state before it is used for the transfer.
+///
+fn teleportation_protocol(noise: Circuit) -> Circuit {
     // Preparing an entangled resource state
 
     let mut entangling_circ = Circuit::new();
@@ -63,27 +79,198 @@ pub fn main() {
 
     // Putting it all together
 
+    entangling_circ + noise + encoding_circ + meas_circ + conditional_circ
+}
+
+/// Returns the register `name` from the `registers` of a circuit run.
+///
+/// Returns an error listing the available registers if there is no register with this name.
+///
+fn extract_register<'a, T>(registers: &'a HashMap<String, T>, name: &str) -> Result<&'a T, String> {
+    registers.get(name).ok_or_else(|| {
+        let mut available: Vec<&String> = registers.keys().collect();
+        available.sort();
+        format!(
+            "Register '{}' not found in the results, available registers: {:?}",
+            name, available
+        )
+    })
+}
+
+/// Runs the teleportation protocol with `runner` and checks the measured state of the receiving qubit.
+///
+/// Only measured bits are used, so any backend implementing `CircuitRunner` can run the protocol.
+/// Errors from running the circuit or extracting the results are returned with a description.
+///
+pub fn teleportation_main(runner: &dyn CircuitRunner) -> Result<(), Box<dyn Error>> {
+    // Sanity check: the entangling and encoding parts before the measurement are unitary
+    let mut unitary_part = Circuit::new();
+    for operation in teleportation_protocol(Circuit::new())
+        .iter()
+        .take_while(|operation| !matches!(operation, ops::Operation::DefinitionBit(_)))
+    {
+        unitary_part.add_operation(operation.clone());
+    }
+    verification::assert_circuit_unitary(&unitary_part, 3)?;
+
+    let mut circuit = teleportation_circuit();
+    circuit += ops::DefinitionBit::new("teleported".to_string(), 1, true);
+    circuit += ops::MeasureQubit::new(2, "teleported".to_string(), 0);
+
+    let result_bit_registers = runner
+        .run(&circuit)
+        .map_err(|error| format!("Running the teleportation circuit failed: {}", error))?;
+    let teleported = extract_register(&result_bit_registers, "teleported")?;
+    println!(
+        "Result bit registers :{:?}",
+        extract_register(&result_bit_registers, "M1M2")?
+    );
+    println!("Measured teleported qubit: {:?}", teleported);
+
+    // Validation check: qubit 2 is in the sent state |1> for every measurement outcome of qubits 0 and 1
+    let teleported_bit = teleported
+        .first()
+        .and_then(|measurement| measurement.first())
+        .ok_or("Register 'teleported' contains no measurement")?;
+    assert!(*teleported_bit);
+
+    // Validation check: a misspelled register name gives an informative error instead of a panic
+    let error = extract_register(&result_bit_registers, "M1M3").unwrap_err();
+    println!("Expected error for a missing register: {}", error);
+    assert!(error.contains("'M1M3' not found") && error.contains("M1M2"));
+    Ok(())
+}
+
+/// Prints the state vector after the teleportation protocol.
+///
+/// The state vector is only available on a simulator, so the circuit runs directly on the QuEST backend.
+///
+fn print_teleported_state() -> Result<(), Box<dyn Error>> {
     let mut verification = Circuit::new();
     verification += ops::DefinitionComplex::new("psi".to_string(), 8, true);
     verification += ops::PragmaGetStateVector::new("psi".to_string(), Some(Circuit::new()));
 
-    let teleportation_circuit = init_circuit
-        + entangling_circ
-        + encoding_circ
-        + meas_circ
-        + conditional_circ
-        + verification;
-
     let backend = Backend::new(3);
-    let result_of_run = backend.run_circuit(&teleportation_circuit);
-    let (result_bit_registers, _result_float_registers, result_complex_registers) =
-        result_of_run.unwrap();
+    let (result_bit_registers, _result_float_registers, result_complex_registers) = backend
+        .run_circuit(&(teleportation_circuit() + verification))
+        .map_err(|error| format!("Running the state vector readout failed: {}", error))?;
 
-    println!("Result bit registers :{:?}", result_bit_registers["M1M2"]);
     println!(
-        "Result complex registers :{:?}",
-        result_complex_registers["psi"]
+        "Result bit registers :{:?}",
+        extract_register(&result_bit_registers, "M1M2")?
     );
+    let amplitudes = extract_register(&result_complex_registers, "psi")?
+        .first()
+        .ok_or("Register 'psi' contains no state vector")?;
+    println!("Teleported state vector:");
+    state_printing::print_statevector(amplitudes, 3);
+    println!("Probabilities of the basis states:");
+    state_printing::print_probabilities(amplitudes, 3);
+    Ok(())
+}
 
+/// Teleports the single-qubit `input_state` with depolarising noise of rate `noise` on the resource state.
+///
+/// Both qubits of the entangled pair are depolarised before the protocol runs. The fidelity
+/// <psi| rho_2 |psi> of the received state rho_2 of qubit 2 with the input state |psi> is returned.
+///
+pub fn teleport_with_noise(input_state: &[Complex64], noise: f64) -> f64 {
+    assert_eq!(input_state.len(), 2, "Input state needs two amplitudes");
+    // Initial density matrix |psi>|00> <00|<psi|, qubit 0 is the lowest bit of the basis index
+    let mut initial = Array2::<Complex64>::zeros((8, 8));
+    initial[[0, 0]] = input_state[0] * input_state[0].conj();
+    initial[[0, 1]] = input_state[0] * input_state[1].conj();
+    initial[[1, 0]] = input_state[1] * input_state[0].conj();
+    initial[[1, 1]] = input_state[1] * input_state[1].conj();
+
+    let mut noise_circ = Circuit::new();
+    for qubit in [1, 2] {
+        noise_circ += ops::PragmaDepolarising::new(
+            qubit,
+            CalculatorFloat::from(1.0),
+            CalculatorFloat::from(noise),
+        );
+    }
+
+    let mut circuit = Circuit::new();
+    circuit += ops::DefinitionComplex::new("rho".to_string(), 64, true);
+    circuit += ops::PragmaSetDensityMatrix::new(initial);
+    circuit += teleportation_protocol(noise_circ);
+    circuit += ops::PragmaGetDensityMatrix::new("rho".to_string(), None);
+
+    let backend = Backend::new(3);
+    let (_result_bit_registers, _result_float_registers, result_complex_registers) =
+        backend.run_circuit(&circuit).unwrap();
+    let rho = &result_complex_registers["rho"][0];
+
+    // Reduced density matrix of qubit 2 (bit 2 of the index), tracing out qubits 0 and 1
+    let mut fidelity = Complex64::new(0.0, 0.0);
+    for a in 0..2 {
+        for b in 0..2 {
+            let element: Complex64 = (0..4)
+                .map(|k| rho[((a << 2) | k) * 8 + ((b << 2) | k)])
+                .sum();
+            fidelity += input_state[a].conj() * element * input_state[b];
+        }
+    }
+    fidelity.re
+}
+
+/// Example teleporting a state over an entangled pair with increasing depolarising noise.
+pub fn teleportation_noisy_main() {
+    println!(">> Noisy teleportation example start.");
+    // |psi> = cos(pi/6) |0> + exp(i pi/4) sin(pi/6) |1>
+    let input_state = [
+        Complex64::new((Pi / 6.0).cos(), 0.0),
+        Complex64::from_polar((Pi / 6.0).sin(), Pi / 4.0),
+    ];
+    let mut fidelities = Vec::new();
+    println!("Noise rate | Fidelity");
+    for noise in [0.0, 0.05, 0.1, 0.2, 0.5, 1.0] {
+        let fidelity = teleport_with_noise(&input_state, noise);
+        println!("{:>10.2} | {:.6}", noise, fidelity);
+        fidelities.push(fidelity);
+    }
+
+    // Validation check: perfect teleportation without noise, a degraded fidelity with noise
+    assert!((fidelities[0] - 1.0).abs() < 1e-10);
+    assert!(fidelities[2] < 1.0 - 1e-3);
+    assert!(fidelities.windows(2).all(|pair| pair[1] < pair[0]));
+    println!(">> Noisy teleportation example end.");
+}
+
+/// Runs the teleportation protocol on a `MockRunner` with canned measurement results.
+///
+/// The result processing is checked without a simulator, including the error for a missing register.
+///
+fn teleportation_mock_check() -> Result<(), Box<dyn Error>> {
+    let mock = MockRunner {
+        registers: HashMap::from([
+            ("M1M2".to_string(), vec![vec![true, false]]),
+            ("teleported".to_string(), vec![vec![true]]),
+        ]),
+    };
+    teleportation_main(&mock)?;
+
+    // Validation check: results without the register of the receiving qubit give an informative error
+    let incomplete = MockRunner {
+        registers: HashMap::from([("M1M2".to_string(), vec![vec![true, false]])]),
+    };
+    let error = teleportation_main(&incomplete).unwrap_err();
+    println!("Expected error for incomplete results: {}", error);
+    assert!(error.to_string().contains("'teleported' not found"));
+    Ok(())
+}
+
+pub fn main() {
+    println!(">> Teleportation example start.");
+    let result = teleportation_main(&QuestRunner::new(3))
+        .and_then(|_| print_teleported_state())
+        .and_then(|_| teleportation_mock_check());
+    if let Err(error) = result {
+        eprintln!("Teleportation example failed: {}", error);
+        std::process::exit(1);
+    }
     println!(">> Teleportation example end.");
+    teleportation_noisy_main();
 }
@@ -0,0 +1,73 @@
+// Copyright © 2021-2022 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use nalgebra::DMatrix;
+use num_complex::Complex64;
+use roqoqo::backends::EvaluatingBackend;
+use roqoqo::{operations::*, Circuit};
+use roqoqo_quest::Backend;
+
+// This module mirrors the unitarity check of `1_Intro_to_roqoqo/src/verification.rs`,
+// each standalone example crate is self-contained.
+// Self-checks for circuits built in the examples.
+// The matrix implemented by a circuit is reconstructed column by column: column j is the state vector
+// obtained by running the circuit on the computational basis state |j>. For a unitary circuit U the
+// product U^dagger U is the identity, any gate breaking unitarity (e.g. a measurement) shows up as a deviation.
+
+/// Maximal Frobenius norm of U^dagger U - I accepted as unitary.
+const UNITARY_TOLERANCE: f64 = 1e-8;
+
+/// Reconstructs the matrix implemented by `circuit` on `num_qubits` qubits.
+///
+/// The basis state |j> is prepared with `PauliX` gates and the resulting state vector
+/// is read out with a `PragmaGetStateVector`.
+///
+pub fn circuit_matrix(circuit: &Circuit, num_qubits: usize) -> Result<DMatrix<Complex64>, String> {
+    let dimension = 2_usize.pow(num_qubits as u32);
+    let backend = Backend::new(num_qubits);
+    let mut matrix = DMatrix::<Complex64>::zeros(dimension, dimension);
+    for column in 0..dimension {
+        let mut readout = Circuit::new();
+        readout += DefinitionComplex::new("psi".to_string(), dimension, true);
+        for qubit in 0..num_qubits {
+            if column & (1 << qubit) != 0 {
+                readout += PauliX::new(qubit);
+            }
+        }
+        readout += circuit.clone();
+        readout += PragmaGetStateVector::new("psi".to_string(), None);
+        let (_result_bit_registers, _result_float_registers, result_complex_registers) = backend
+            .run_circuit(&readout)
+            .map_err(|error| format!("Running the circuit failed: {:?}", error))?;
+        for (row, amplitude) in result_complex_registers["psi"][0].iter().enumerate() {
+            matrix[(row, column)] = *amplitude;
+        }
+    }
+    Ok(matrix)
+}
+
+/// Checks that `circuit` implements a unitary operation on `num_qubits` qubits.
+///
+/// Returns an error with the Frobenius norm of U^dagger U - I if it exceeds the tolerance.
+///
+pub fn assert_circuit_unitary(circuit: &Circuit, num_qubits: usize) -> Result<(), String> {
+    let matrix = circuit_matrix(circuit, num_qubits)?;
+    let identity = DMatrix::<Complex64>::identity(matrix.nrows(), matrix.ncols());
+    let deviation = (matrix.adjoint() * &matrix - identity).norm();
+    if deviation > UNITARY_TOLERANCE {
+        return Err(format!(
+            "Circuit is not unitary: the Frobenius norm of U^dagger U - I is {:.3e}, the tolerance {:.0e}",
+            deviation, UNITARY_TOLERANCE
+        ));
+    }
+    Ok(())
+}